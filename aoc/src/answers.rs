@@ -0,0 +1,115 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+const HEADER: &str = "\
+# Known-correct answers, checked by `aoc verify`.
+#
+# Each answer is keyed by the hash of the input it was produced from, so
+# answers for more than one account's inputs can live side by side.
+# Record new answers with `aoc verify --record`. The answers for the inputs in
+# `examples/` are checked in.
+
+";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    answers: Vec<Answer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Answer {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub expected: String,
+}
+
+impl Answers {
+    /// Read the answers held in `path`; a missing file holds no answers.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read from {}", path.display()))?;
+        toml::from_str(&text).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = format!("{}{}", HEADER, toml::to_string(self)?);
+        fs::write(path, text).with_context(|| format!("Failed to write to {}", path.display()))
+    }
+
+    pub fn expected(&self, day: u32, part: u32, input: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|answer| answer.day == day && answer.part == part && answer.input == input)
+            .map(|answer| answer.expected.as_str())
+    }
+
+    /// Store `expected` as the answer, replacing any previously recorded one.
+    pub fn record(&mut self, day: u32, part: u32, input: &str, expected: &str) {
+        self.answers
+            .retain(|answer| !(answer.day == day && answer.part == part && answer.input == input));
+        self.answers.push(Answer {
+            day,
+            part,
+            input: input.to_string(),
+            expected: expected.to_string(),
+        });
+        self.answers
+            .sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+    }
+}
+
+/// A short, stable identifier for an input: its 64-bit FNV-1a hash.
+pub fn input_id(input: &[u8]) -> String {
+    let hash = input.iter().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_id_is_stable() {
+        assert_eq!(input_id(b""), "cbf29ce484222325");
+        assert_eq!(input_id(b"a"), "af63dc4c8601ec8c");
+        assert_ne!(input_id(b"199\n200\n"), input_id(b"199\n201\n"));
+    }
+
+    #[test]
+    fn record_and_round_trip() {
+        let mut answers = Answers::default();
+        answers.record(2, 1, "abc", "150");
+        answers.record(1, 2, "abc", "5");
+        answers.record(1, 2, "abc", "6");
+        assert_eq!(answers.expected(1, 2, "abc"), Some("6"));
+        assert_eq!(answers.expected(1, 2, "def"), None);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers.toml");
+        answers.save(&path).unwrap();
+        let loaded = Answers::load(&path).unwrap();
+        assert_eq!(loaded.answers, answers.answers);
+        assert_eq!(loaded.answers[0].day, 1);
+    }
+
+    #[test]
+    fn checked_in_header() {
+        // Recording answers rewrites the header, so it must not change it.
+        assert!(include_str!("../../answers.toml").starts_with(HEADER));
+    }
+
+    #[test]
+    fn missing_file_is_empty() {
+        let answers = Answers::load(Path::new("does-not-exist.toml")).unwrap();
+        assert!(answers.answers.is_empty());
+    }
+}
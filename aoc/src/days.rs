@@ -0,0 +1,78 @@
+use anyhow::Result;
+use utils::Solver;
+
+/// A single puzzle part that the runner knows how to execute.
+pub struct Entry {
+    pub day: u32,
+    pub part: u32,
+    pub run: fn(&str, u32) -> Result<String>,
+}
+
+fn run<S: Solver>(filename: &str, part: u32) -> Result<String> {
+    let mut solution = S::load(filename)?;
+    Ok(solution.solve(part)?.to_string())
+}
+
+macro_rules! entry {
+    ($day:literal, $part:literal, $krate:ident) => {
+        Entry {
+            day: $day,
+            part: $part,
+            run: run::<$krate::Solution>,
+        }
+    };
+}
+
+pub fn all() -> Vec<Entry> {
+    vec![
+        entry!(1, 1, d1),
+        entry!(1, 2, d1),
+        entry!(2, 1, d2),
+        entry!(2, 2, d2),
+        entry!(3, 1, d3),
+        entry!(3, 2, d3),
+        entry!(4, 1, d4),
+        entry!(4, 2, d4),
+        entry!(5, 1, d5),
+        entry!(5, 2, d5),
+        entry!(6, 1, d6),
+        entry!(6, 2, d6),
+        entry!(7, 1, d7),
+        entry!(7, 2, d7),
+        entry!(8, 1, d8),
+        entry!(8, 2, d8),
+        entry!(9, 1, d9),
+        entry!(9, 2, d9),
+        entry!(10, 1, d10),
+        entry!(10, 2, d10),
+        entry!(11, 1, d11),
+        entry!(11, 2, d11),
+        entry!(12, 1, d12),
+        entry!(12, 2, d12),
+        entry!(13, 1, d13),
+        entry!(13, 2, d13),
+        entry!(14, 1, d14),
+        entry!(14, 2, d14),
+        entry!(15, 1, d15),
+        entry!(15, 2, d15),
+        entry!(16, 1, d16),
+        entry!(16, 2, d16),
+        entry!(17, 1, d17),
+        entry!(17, 2, d17),
+        entry!(18, 1, d18),
+        entry!(18, 2, d18),
+        entry!(19, 1, d19),
+        entry!(19, 2, d19),
+        entry!(20, 1, d20),
+        entry!(20, 2, d20),
+        entry!(21, 1, d21),
+        entry!(21, 2, d21),
+        entry!(22, 1, d22),
+        entry!(22, 2, d22),
+        entry!(23, 1, d23),
+        entry!(23, 2, d23),
+        entry!(24, 1, d24),
+        entry!(24, 2, d24),
+        entry!(25, 1, d25),
+    ]
+}
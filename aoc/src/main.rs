@@ -0,0 +1,340 @@
+use answers::{input_id, Answers};
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::fs;
+use std::ops::RangeInclusive;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use utils::input::Inputs;
+
+mod answers;
+mod days;
+mod report;
+mod scaffold;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2021 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, a range of days, or all of them, and print a results table
+    Run {
+        /// A single day (`15`), an inclusive range (`3-7`) or `all`
+        days: Days,
+        /// Only run this part; both parts are run when omitted
+        part: Option<u32>,
+        /// Input file to use, instead of looking it up in the input cache
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Input cache directory, overriding `AOC_INPUTS`
+        #[arg(long)]
+        input_dir: Option<PathBuf>,
+    },
+    /// Run solutions against their full inputs, and check them against the known answers
+    Verify {
+        /// A single day (`15`), an inclusive range (`3-7`) or `all`
+        #[arg(default_value = "all")]
+        days: Days,
+        /// File holding the known answers
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Input cache directory, overriding `AOC_INPUTS`
+        #[arg(long)]
+        input_dir: Option<PathBuf>,
+        /// Store the answers for any input that has none recorded yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Download puzzle inputs that are not yet in the input cache
+    Fetch {
+        /// A single day (`15`), an inclusive range (`3-7`) or `all`
+        days: Days,
+        /// Input cache directory, overriding `AOC_INPUTS`
+        #[arg(long)]
+        input_dir: Option<PathBuf>,
+    },
+    /// Summarise the results of `cargo bench` as CSV or JSON
+    Report {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Csv)]
+        format: Format,
+        /// Directory criterion wrote its results to
+        #[arg(long, default_value = "target/criterion")]
+        criterion_dir: PathBuf,
+        /// File to write the report to, instead of standard output
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Create a crate for a new day from `template`, and register it with the runner
+    NewDay {
+        /// The day to create
+        day: u32,
+        /// File holding the puzzle's example input, to seed the test module with
+        #[arg(long)]
+        example: Option<PathBuf>,
+        /// The answer part 1 should give for the example input
+        #[arg(long)]
+        answer: Option<i64>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Format {
+    Csv,
+    Json,
+}
+
+#[derive(Clone, Debug)]
+struct Days(RangeInclusive<u32>);
+
+impl FromStr for Days {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let range = match s.split_once('-') {
+            _ if s == "all" => 1..=25,
+            Some((start, end)) => start.parse()?..=end.parse()?,
+            None => {
+                let day = s.parse()?;
+                day..=day
+            }
+        };
+        if range.is_empty() || *range.start() < 1 || *range.end() > 25 {
+            bail!("days must be within 1-25, got {}", s);
+        }
+        Ok(Self(range))
+    }
+}
+
+fn main() -> Result<()> {
+    env_logger::init();
+
+    match Cli::parse().command {
+        Command::Run {
+            days,
+            part,
+            input,
+            input_dir,
+        } => run(days, part, input, &inputs(input_dir)),
+        Command::Verify {
+            days,
+            answers,
+            input_dir,
+            record,
+        } => verify(days, &answers, &inputs(input_dir), record),
+        Command::Fetch { days, input_dir } => fetch(days, &inputs(input_dir)),
+        Command::Report {
+            format,
+            criterion_dir,
+            output,
+        } => report(format, &criterion_dir, output.as_deref()),
+        Command::NewDay {
+            day,
+            example,
+            answer,
+        } => new_day(day, example.as_deref(), answer),
+    }
+}
+
+fn new_day(day: u32, example: Option<&Path>, answer: Option<i64>) -> Result<()> {
+    let example = example
+        .map(|path| {
+            fs::read_to_string(path)
+                .with_context(|| format!("Failed to read from {}", path.display()))
+        })
+        .transpose()?;
+    let new = scaffold::NewDay {
+        day,
+        example,
+        answer,
+    };
+    let created = scaffold::new_day(Path::new("."), &new)?;
+    println!("Created {}", created.display());
+    Ok(())
+}
+
+fn report(format: Format, criterion_dir: &Path, output: Option<&Path>) -> Result<()> {
+    let timings = report::collect(criterion_dir)?;
+    let report = match format {
+        Format::Csv => report::to_csv(&timings),
+        Format::Json => report::to_json(&timings)?,
+    };
+    match output {
+        Some(output) => fs::write(output, report)
+            .with_context(|| format!("Failed to write to {}", output.display())),
+        None => {
+            print!("{}", report);
+            Ok(())
+        }
+    }
+}
+
+fn inputs(input_dir: Option<PathBuf>) -> Inputs {
+    let inputs = Inputs::from_env();
+    match input_dir {
+        Some(input_dir) => inputs.with_cache_dir(input_dir),
+        None => inputs,
+    }
+}
+
+fn fetch(days: Days, inputs: &Inputs) -> Result<()> {
+    for day in days.0 {
+        let path = inputs.resolve(day)?;
+        println!("{:>3} | {}", day, path.display());
+    }
+    Ok(())
+}
+
+fn run(days: Days, part: Option<u32>, input: Option<PathBuf>, inputs: &Inputs) -> Result<()> {
+    let entries = days::all()
+        .into_iter()
+        .filter(|entry| days.0.contains(&entry.day))
+        .filter(|entry| part.is_none_or(|part| entry.part == part))
+        .collect::<Vec<_>>();
+    if entries.is_empty() {
+        bail!("No solutions match the requested day and part");
+    }
+    if input.is_some() && entries.len() > 1 {
+        bail!("--input can only be used when running a single part");
+    }
+
+    // Failures are reported in the table; keep the default hook from
+    // interleaving panic messages with it.
+    panic::set_hook(Box::new(|_| {}));
+
+    println!(
+        "{:>3} | {:>4} | {:<20} | {:>12}",
+        "Day", "Part", "Answer", "Time"
+    );
+    println!("{:-<4}+{:-<6}+{:-<22}+{:-<13}", "", "", "", "");
+    let mut failures = 0;
+    for entry in &entries {
+        let filename = match &input {
+            Some(input) => Ok(input.to_owned()),
+            None => inputs.resolve(entry.day),
+        };
+        let (answer, elapsed) = match filename {
+            Ok(filename) => execute(entry, &filename),
+            Err(e) => (Err(e), Duration::ZERO),
+        };
+        let answer = answer.unwrap_or_else(|e| {
+            failures += 1;
+            format!("error: {:#}", e)
+        });
+        println!(
+            "{:>3} | {:>4} | {:<20} | {:>12}",
+            entry.day,
+            entry.part,
+            answer,
+            format!("{:.3?}", elapsed)
+        );
+    }
+
+    let _ = panic::take_hook();
+    if failures > 0 {
+        bail!("{} of {} parts failed", failures, entries.len());
+    }
+    Ok(())
+}
+
+fn verify(days: Days, answers_path: &Path, inputs: &Inputs, record: bool) -> Result<()> {
+    let mut answers = Answers::load(answers_path)?;
+    let entries = days::all()
+        .into_iter()
+        .filter(|entry| days.0.contains(&entry.day))
+        .collect::<Vec<_>>();
+
+    panic::set_hook(Box::new(|_| {}));
+
+    println!(
+        "{:>3} | {:>4} | {:<7} | {:<20} | Expected",
+        "Day", "Part", "Status", "Answer"
+    );
+    println!("{:-<4}+{:-<6}+{:-<9}+{:-<22}+{:-<21}", "", "", "", "", "");
+    let (mut failed, mut changed, mut new, mut missing) = (0, 0, 0, 0);
+    for entry in &entries {
+        let outcome = inputs.resolve(entry.day).and_then(|filename| {
+            let id = input_id(
+                &fs::read(&filename)
+                    .with_context(|| format!("Failed to read from {}", filename.display()))?,
+            );
+            let (answer, _) = execute(entry, &filename);
+            Ok((id, answer?))
+        });
+        let (status, answer, expected) = match outcome {
+            Err(e) => {
+                failed += 1;
+                ("fail", format!("error: {:#}", e), String::new())
+            }
+            Ok((id, answer)) => match answers.expected(entry.day, entry.part, &id) {
+                Some(expected) if expected == answer => ("pass", answer, expected.to_string()),
+                Some(expected) => {
+                    changed += 1;
+                    ("changed", answer, expected.to_string())
+                }
+                None if record => {
+                    new += 1;
+                    answers.record(entry.day, entry.part, &id, &answer);
+                    ("new", answer, String::new())
+                }
+                None => {
+                    missing += 1;
+                    ("missing", answer, String::new())
+                }
+            },
+        };
+        println!(
+            "{:>3} | {:>4} | {:<7} | {:<20} | {}",
+            entry.day, entry.part, status, answer, expected
+        );
+    }
+
+    let _ = panic::take_hook();
+    if record && new > 0 {
+        answers.save(answers_path)?;
+        println!("Recorded {} new answers in {}", new, answers_path.display());
+    }
+    if missing > 0 {
+        println!(
+            "{} answers have nothing recorded to check them against; store them with --record",
+            missing
+        );
+    }
+    if failed > 0 || changed > 0 || missing > 0 {
+        bail!(
+            "{} failed, {} changed and {} unrecorded of {} parts",
+            failed,
+            changed,
+            missing,
+            entries.len()
+        );
+    }
+    Ok(())
+}
+
+fn execute(entry: &days::Entry, filename: &Path) -> (Result<String>, Duration) {
+    let start = Instant::now();
+    let answer = filename
+        .to_str()
+        .with_context(|| format!("Input path {:?} is not valid UTF-8", filename))
+        .and_then(|filename| {
+            if !Path::new(filename).is_file() {
+                bail!("missing input {}", filename);
+            }
+            panic::catch_unwind(|| (entry.run)(filename, entry.part)).unwrap_or_else(|payload| {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "unknown panic".to_string());
+                bail!("panicked: {}", message)
+            })
+        });
+    (answer, start.elapsed())
+}
@@ -0,0 +1,143 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Timing of one benchmark phase, taken from criterion's latest estimates.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Timing {
+    pub day: u32,
+    pub part: u32,
+    pub phase: String,
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub std_dev_ns: f64,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    mean: Estimate,
+    median: Estimate,
+    std_dev: Estimate,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+}
+
+/// Collect the `dNpM/<phase>` results that criterion wrote under `criterion_dir`.
+pub fn collect(criterion_dir: &Path) -> Result<Vec<Timing>> {
+    let mut timings = Vec::new();
+    let groups = fs::read_dir(criterion_dir)
+        .with_context(|| format!("Failed to read from {}", criterion_dir.display()))?;
+    for group in groups {
+        let group = group?;
+        let (day, part) = match parse_group(&group.file_name().to_string_lossy()) {
+            Some(id) => id,
+            None => continue,
+        };
+        for phase in fs::read_dir(group.path())? {
+            let phase = phase?;
+            let estimates = phase.path().join("new").join("estimates.json");
+            if !estimates.is_file() {
+                continue;
+            }
+            let text = fs::read_to_string(&estimates)?;
+            let estimates: Estimates = serde_json::from_str(&text)
+                .with_context(|| format!("Failed to parse {}", estimates.display()))?;
+            timings.push(Timing {
+                day,
+                part,
+                phase: phase.file_name().to_string_lossy().to_string(),
+                mean_ns: estimates.mean.point_estimate,
+                median_ns: estimates.median.point_estimate,
+                std_dev_ns: estimates.std_dev.point_estimate,
+            });
+        }
+    }
+    timings.sort_by(|a, b| (a.day, a.part, &a.phase).cmp(&(b.day, b.part, &b.phase)));
+    Ok(timings)
+}
+
+fn parse_group(name: &str) -> Option<(u32, u32)> {
+    let (day, part) = name.strip_prefix('d')?.split_once('p')?;
+    Some((day.parse().ok()?, part.parse().ok()?))
+}
+
+pub fn to_csv(timings: &[Timing]) -> String {
+    let mut csv = String::from("day,part,phase,mean_ns,median_ns,std_dev_ns\n");
+    for timing in timings {
+        csv += &format!(
+            "{},{},{},{:.1},{:.1},{:.1}\n",
+            timing.day,
+            timing.part,
+            timing.phase,
+            timing.mean_ns,
+            timing.median_ns,
+            timing.std_dev_ns
+        );
+    }
+    csv
+}
+
+pub fn to_json(timings: &[Timing]) -> Result<String> {
+    Ok(serde_json::to_string_pretty(timings)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_estimates(root: &Path, group: &str, phase: &str, mean: f64) {
+        let dir = root.join(group).join(phase).join("new");
+        fs::create_dir_all(&dir).unwrap();
+        let estimate = |value: f64| {
+            format!(
+                r#"{{"confidence_interval":{{"confidence_level":0.95,"lower_bound":{0},"upper_bound":{0}}},"point_estimate":{0},"standard_error":0.0}}"#,
+                value
+            )
+        };
+        fs::write(
+            dir.join("estimates.json"),
+            format!(
+                r#"{{"mean":{},"median":{},"median_abs_dev":{},"slope":null,"std_dev":{}}}"#,
+                estimate(mean),
+                estimate(mean - 1.0),
+                estimate(0.5),
+                estimate(2.0)
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn collects_criterion_output() {
+        let root = tempfile::tempdir().unwrap();
+        write_estimates(root.path(), "d15p2", "solve", 2000.0);
+        write_estimates(root.path(), "d15p2", "parse", 100.0);
+        write_estimates(root.path(), "d2p1", "parse", 10.0);
+        fs::create_dir_all(root.path().join("report")).unwrap();
+
+        let timings = collect(root.path()).unwrap();
+        assert_eq!(
+            timings
+                .iter()
+                .map(|t| (t.day, t.part, t.phase.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(2, 1, "parse"), (15, 2, "parse"), (15, 2, "solve")]
+        );
+        assert_eq!(
+            to_csv(&timings).lines().nth(3),
+            Some("15,2,solve,2000.0,1999.0,2.0")
+        );
+        assert!(to_json(&timings).unwrap().contains(r#""phase": "solve""#));
+    }
+
+    #[test]
+    fn parse_group_names() {
+        assert_eq!(parse_group("d25p1"), Some((25, 1)));
+        assert_eq!(parse_group("report"), None);
+        assert_eq!(parse_group("dxp1"), None);
+    }
+}
@@ -0,0 +1,288 @@
+use anyhow::{bail, ensure, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// What `aoc new-day` should create.
+#[derive(Debug, Default)]
+pub struct NewDay {
+    pub day: u32,
+    /// The puzzle's example input, for the test module.
+    pub example: Option<String>,
+    /// The answer the example should give to part 1.
+    pub answer: Option<i64>,
+}
+
+/// Create the `dN` crate under the workspace `root`, and register it with
+/// the workspace and the `aoc` runner. Returns the new crate's directory.
+pub fn new_day(root: &Path, new: &NewDay) -> Result<PathBuf> {
+    ensure!(
+        (1..=25).contains(&new.day),
+        "days must be within 1-25, got {}",
+        new.day
+    );
+    let name = format!("d{}", new.day);
+    let target = root.join(&name);
+    if target.exists() {
+        bail!("{} already exists", target.display());
+    }
+    let source_dir = root.join("template");
+    if !source_dir.is_dir() {
+        bail!(
+            "No template crate in {}; run this from the workspace root",
+            root.display()
+        );
+    }
+
+    copy_crate(&source_dir, &target, &|text| {
+        let mut text = text
+            .replace("template", &name)
+            .replace("from_args(0)", &format!("from_args({})", new.day))
+            .replace(
+                "solver::<Solution>(c, 0, ",
+                &format!("solver::<Solution>(c, {}, ", new.day),
+            );
+        if let Some(example) = &new.example {
+            text = text.replace(r#"const EXAMPLE: &str = "";"#, &example_const(example));
+        }
+        if let Some(answer) = new.answer {
+            text = text.replace(
+                "assert_eq!(solution.part1().unwrap(), 0);",
+                &format!("assert_eq!(solution.part1().unwrap(), {});", answer),
+            );
+        }
+        text
+    })?;
+
+    register(
+        &root.join("Cargo.toml"),
+        new.day,
+        |line| line.trim_start().starts_with('"'),
+        &[format!("    \"{}\",", name)],
+    )?;
+    register(
+        &root.join("aoc").join("Cargo.toml"),
+        new.day,
+        |line| line.contains("path = "),
+        &[format!("{0} = {{path = \"../{0}\"}}", name)],
+    )?;
+    register(
+        &root.join("aoc").join("src").join("days.rs"),
+        new.day,
+        |line| line.trim_start().starts_with("entry!("),
+        &[1, 2].map(|part| format!("        entry!({}, {}, {}),", new.day, part, name)),
+    )?;
+    Ok(target)
+}
+
+/// Copy a crate's sources, passing every file's text through `rewrite` and
+/// renaming files named after the crate.
+fn copy_crate(from: &Path, to: &Path, rewrite: &dyn Fn(&str) -> String) -> Result<()> {
+    let from_name = from.file_name().unwrap_or_default().to_string_lossy();
+    let to_name = to.file_name().unwrap_or_default().to_string_lossy();
+    copy_dir(from, to, &from_name, &to_name, rewrite)
+}
+
+fn copy_dir(
+    from: &Path,
+    to: &Path,
+    from_name: &str,
+    to_name: &str,
+    rewrite: &dyn Fn(&str) -> String,
+) -> Result<()> {
+    fs::create_dir_all(to).with_context(|| format!("Failed to create {}", to.display()))?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if file_name == "target" {
+            continue;
+        }
+        let destination = to.join(file_name.replace(from_name, to_name));
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &destination, from_name, to_name, rewrite)?;
+        } else {
+            let text = fs::read_to_string(entry.path())
+                .with_context(|| format!("Failed to read from {}", entry.path().display()))?;
+            fs::write(&destination, rewrite(&text))
+                .with_context(|| format!("Failed to write to {}", destination.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// The example input as a constant, laid out just as it is in the puzzle.
+fn example_const(example: &str) -> String {
+    let mut example = example.replace('\\', "\\\\").replace('"', "\\\"");
+    if !example.ends_with('\n') {
+        example.push('\n');
+    }
+    // An escaped line break starts the text on its own line, but would also
+    // swallow any indentation the first line has.
+    let start = if example.starts_with(char::is_whitespace) {
+        ""
+    } else {
+        "\\\n"
+    };
+    format!("const EXAMPLE: &str = \"{}{}\";", start, example)
+}
+
+/// Add `entries` for `day` to the list in `path` made of the lines `is_entry`
+/// picks out, keeping the days in order. Nothing is added if the day is
+/// already listed.
+fn register(
+    path: &Path,
+    day: u32,
+    is_entry: impl Fn(&str) -> bool,
+    entries: &[String],
+) -> Result<()> {
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read from {}", path.display()))?;
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| is_entry(line))
+        .filter_map(|(index, line)| day_of(line).map(|day| (index, day)))
+        .collect::<Vec<_>>();
+    if days.iter().any(|(_, listed)| *listed == day) {
+        return Ok(());
+    }
+    let position = match days.iter().rev().find(|(_, listed)| *listed < day) {
+        Some((index, _)) => index + 1,
+        None => match days.first() {
+            Some((index, _)) => *index,
+            None => bail!("Found no days listed in {}", path.display()),
+        },
+    };
+    lines.splice(position..position, entries.iter().map(String::as_str));
+    fs::write(path, lines.join("\n") + "\n")
+        .with_context(|| format!("Failed to write to {}", path.display()))
+}
+
+/// The day of the first `dN` crate named in `line`.
+fn day_of(line: &str) -> Option<u32> {
+    line.split(|c: char| !c.is_ascii_alphanumeric())
+        .find_map(|word| word.strip_prefix('d')?.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A workspace holding the real template, and just enough of the
+    /// registration files to add to.
+    fn workspace() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        let template = Path::new(env!("CARGO_MANIFEST_DIR")).join("../template");
+        copy_crate(&template, &root.path().join("template"), &|text| {
+            text.to_string()
+        })
+        .unwrap();
+        fs::write(
+            root.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"utils\",\n    \"d1\",\n    \"d3\",\n]\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.path().join("aoc/src")).unwrap();
+        fs::write(
+            root.path().join("aoc/Cargo.toml"),
+            "[dependencies]\nd1 = {path = \"../d1\"}\nd3 = {path = \"../d3\"}\n",
+        )
+        .unwrap();
+        fs::write(
+            root.path().join("aoc/src/days.rs"),
+            "    vec![\n        entry!(1, 1, d1),\n        entry!(1, 2, d1),\n        entry!(3, 1, d3),\n    ]\n",
+        )
+        .unwrap();
+        root
+    }
+
+    fn read(root: &Path, path: &str) -> String {
+        fs::read_to_string(root.join(path)).unwrap()
+    }
+
+    #[test]
+    fn from_template() {
+        let root = workspace();
+        let new = NewDay {
+            day: 2,
+            example: Some("forward 5\ndown \"5\"\n".to_string()),
+            answer: Some(150),
+        };
+        new_day(root.path(), &new).unwrap();
+
+        let root = root.path();
+        assert!(read(root, "d2/Cargo.toml").contains("name = \"d2\""));
+        assert!(read(root, "d2/src/main.rs").contains("Solution::from_args(2)?"));
+        let bench = read(root, "d2/benches/d2.rs");
+        assert!(bench.contains("solver::<Solution>(c, 2, 1)"));
+        assert!(bench.contains("solver::<Solution>(c, 2, 2)"));
+        let lib = read(root, "d2/src/lib.rs");
+        assert!(lib.contains("    const EXAMPLE: &str = \"\\\nforward 5\ndown \\\"5\\\"\n\";"));
+        assert!(lib.contains("assert_eq!(solution.part1().unwrap(), 150);"));
+
+        assert_eq!(
+            read(root, "Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"utils\",\n    \"d1\",\n    \"d2\",\n    \"d3\",\n]\n"
+        );
+        assert!(read(root, "aoc/Cargo.toml")
+            .contains("d1 = {path = \"../d1\"}\nd2 = {path = \"../d2\"}\nd3"));
+        assert!(read(root, "aoc/src/days.rs").contains(
+            "entry!(1, 2, d1),\n        entry!(2, 1, d2),\n        entry!(2, 2, d2),\n        entry!(3, 1"
+        ));
+
+        let error = new_day(root, &new).unwrap_err();
+        assert!(error.to_string().ends_with("d2 already exists"));
+    }
+
+    #[test]
+    fn after_the_last_day() {
+        let root = workspace();
+        let new = NewDay {
+            day: 12,
+            ..Default::default()
+        };
+        new_day(root.path(), &new).unwrap();
+
+        let root = root.path();
+        assert!(read(root, "d12/src/lib.rs").contains(r#"const EXAMPLE: &str = "";"#));
+        assert!(read(root, "Cargo.toml").ends_with("    \"d3\",\n    \"d12\",\n]\n"));
+        assert!(read(root, "aoc/src/days.rs")
+            .contains("entry!(12, 1, d12),\n        entry!(12, 2, d12),\n    ]"));
+    }
+
+    #[test]
+    fn needs_the_template() {
+        let root = tempfile::tempdir().unwrap();
+        let new = NewDay {
+            day: 12,
+            ..Default::default()
+        };
+        let error = new_day(root.path(), &new).unwrap_err();
+        assert!(error.to_string().starts_with("No template crate in"));
+    }
+
+    #[test]
+    fn only_advent_days() {
+        let root = workspace();
+        for day in [0, 26] {
+            let new = NewDay {
+                day,
+                ..Default::default()
+            };
+            assert_eq!(
+                new_day(root.path(), &new).unwrap_err().to_string(),
+                format!("days must be within 1-25, got {}", day)
+            );
+            assert!(!root.path().join(format!("d{}", day)).exists());
+        }
+        assert!(!read(root.path(), "Cargo.toml").contains("d0"));
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(day_of("    \"d12\","), Some(12));
+        assert_eq!(day_of("entry!(25, 1, d25),"), Some(25));
+        assert_eq!(day_of("d7 = {path = \"../d7\"}"), Some(7));
+        assert_eq!(day_of("    \"utils\","), None);
+    }
+}
@@ -0,0 +1,78 @@
+use anyhow::Result;
+use log::debug;
+use std::io::BufRead;
+use utils::{parse_at, Solver};
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut solution = Solution::new();
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let reading = line.trim();
+            if reading.is_empty() {
+                continue;
+            }
+            let reading =
+                parse_at(&line, reading, "a depth").map_err(|e| e.with_line(number + 1))?;
+            solution.add(reading);
+        }
+        Ok(solution)
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        Ok(count_increases(self.readings.iter().copied()))
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        let sums = self.readings.windows(3).map(sum).collect::<Vec<_>>();
+        debug!("{:?}", sums);
+        Ok(count_increases(sums.into_iter()))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Solution {
+    readings: Vec<i32>,
+}
+
+impl Solution {
+    fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    fn add(&mut self, reading: i32) {
+        self.readings.push(reading);
+    }
+}
+
+fn count_increases(readings: impl Iterator<Item = i32>) -> i64 {
+    readings
+        .fold(None, accumulate)
+        .map(|(_, count)| count)
+        .unwrap_or(0) as i64
+}
+
+fn sum(values: &[i32]) -> i32 {
+    let mut total = 0;
+    for value in values {
+        total += value;
+    }
+    total
+}
+
+fn accumulate(acc: Option<(i32, i32)>, reading: i32) -> Option<(i32, i32)> {
+    debug!("reading: {}", reading);
+    match acc {
+        Some((last, count)) if reading > last => Some((reading, count + 1)),
+        Some((_last, count)) => Some((reading, count)),
+        None => Some((reading, 0)),
+    }
+}
@@ -0,0 +1,14 @@
+use anyhow::Result;
+use d1::Solution;
+use log::info;
+use utils::Solver;
+
+fn main() -> Result<()> {
+    env_logger::init();
+
+    let (part, mut solution) = Solution::from_args(1)?;
+    info!("solution: {:?}", solution);
+    info!("Count = {}", solution.solve(part)?);
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+use anyhow::{Context, Result};
+use log::debug;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use itertools::Itertools;
+use utils::{ParseError, Solver};
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut solution = Solution::new();
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = Line::from_str(&line).map_err(|e| e.with_line(number + 1))?;
+            solution.add(line);
+        }
+
+        Ok(solution)
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        let mut score = 0;
+        for line in &self.data {
+            if let Status::Corrupted(x) = self.check(line) {
+                score += self.calculate_score(x);
+            }
+        }
+        Ok(score)
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        let mut autocomplete_scores = Vec::new();
+        for line in &self.data {
+            let mut opened = match self.check(line) {
+                Status::Incomplete(opened) => opened,
+                Status::Corrupted(_) => continue,
+            };
+            debug!("incomplete: {:?} {:?}", line, opened);
+            let mut score = 0;
+            loop {
+                match opened.pop() {
+                    None => break,
+                    Some(c) => {
+                        score *= 5;
+                        let c = self.counterpoint(c);
+                        score += match c {
+                            ')' => 1,
+                            ']' => 2,
+                            '}' => 3,
+                            '>' => 4,
+                            _ => panic!(),
+                        };
+                    }
+                }
+            }
+            debug!("line autocomplete score = {}", score);
+            autocomplete_scores.push(score);
+        }
+        let answer_pos = autocomplete_scores.len() / 2;
+        let answer = *autocomplete_scores
+            .iter()
+            .sorted()
+            .nth(answer_pos)
+            .context("Every line is corrupted")?;
+        debug!("{}", answer);
+        Ok(answer)
+    }
+}
+
+#[derive(Debug)]
+struct Line {
+    data: Vec<char>,
+}
+
+/// Whether a line is corrupted, and by which character, or else which
+/// chunks it leaves open.
+enum Status<'a> {
+    Corrupted(&'a char),
+    Incomplete(Vec<&'a char>),
+}
+
+#[derive(Debug, Default)]
+pub struct Solution {
+    data: Vec<Line>,
+}
+
+impl Solution {
+    fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    fn add(&mut self, line: Line) {
+        self.data.push(line);
+    }
+
+    fn matched_pair(&self, a: &char, b: &char) -> bool {
+        matches!((a, b), ('(', ')') | ('[', ']') | ('{', '}') | ('<', '>'))
+    }
+
+    fn calculate_score(&self, c: &char) -> i64 {
+        match c {
+            ')' => 3,
+            ']' => 57,
+            '}' => 1197,
+            '>' => 25137,
+            _ => panic!(),
+        }
+    }
+
+    fn counterpoint(&self, c: &char) -> char {
+        match c {
+            '(' => ')',
+            '[' => ']',
+            '{' => '}',
+            '<' => '>',
+            _ => panic!(),
+        }
+    }
+
+    fn check<'a>(&self, line: &'a Line) -> Status<'a> {
+        let mut opened = Vec::new();
+        for (pos, x) in line.data.iter().enumerate() {
+            let illegal = match x {
+                '(' | '[' | '{' | '<' => {
+                    opened.push(x);
+                    false
+                }
+                _ => match opened.pop() {
+                    None => true,
+                    Some(y) if !self.matched_pair(y, x) => true,
+                    _ => false,
+                },
+            };
+            if illegal {
+                debug!("illegal {},{} in {:?}", pos, x, line);
+                return Status::Corrupted(x);
+            }
+        }
+        Status::Incomplete(opened)
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = s.trim();
+        let data = line
+            .char_indices()
+            .map(|(i, c)| match c {
+                '(' | ')' | '[' | ']' | '{' | '}' | '<' | '>' => Ok(c),
+                _ => Err(ParseError::at(s, &line[i..i + c.len_utf8()], "a bracket")),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Line { data })
+    }
+}
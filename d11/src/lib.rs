@@ -0,0 +1,135 @@
+use anyhow::{Context, Result};
+use log::debug;
+use std::collections::HashSet;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use utils::{Grid, Neighbourhood, Solver};
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
+
+    fn parse<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        let grid = Grid::<u8>::from_str(&input)?;
+
+        Ok(Solution::new(grid.map(|v| *v as i64)))
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        let flashes = (1..=100).map(|_| self.step()).sum();
+        debug!("{}", flashes);
+        Ok(flashes)
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        let total_cells = (self.data.width() * self.data.height()) as i64;
+        (1..=2000)
+            .find(|_| self.step() == total_cells)
+            .context("The octopuses never all flash at once")
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Solution {
+    data: Grid<i64>,
+}
+
+impl Solution {
+    fn new(data: Grid<i64>) -> Self {
+        Self { data }
+    }
+
+    fn flash(&mut self) {
+        let (width, height) = (self.data.width() as isize, self.data.height() as isize);
+
+        let mut flashed = HashSet::new();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for y in 0..height {
+                for x in 0..width {
+                    if let Some(score) = self.data.get(x, y) {
+                        if *score > 9 && !flashed.contains(&(x, y)) {
+                            changed = true;
+                            flashed.insert((x, y));
+                            let around = self
+                                .data
+                                .neighbours(x, y, Neighbourhood::WithDiagonals)
+                                .map(|(point, _)| point)
+                                .collect::<Vec<_>>();
+                            for (x, y) in around {
+                                *self.data.get_mut(x, y).unwrap() += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn display(&self) {
+        debug!("\n{}", self.data);
+    }
+
+    /// Advance by one step, returning how many octopuses flashed.
+    fn step(&mut self) -> i64 {
+        let (width, height) = (self.data.width() as isize, self.data.height() as isize);
+
+        // Increment all energy
+        for y in 0..height {
+            for x in 0..width {
+                let value = self.data.get(x, y).unwrap() + 1;
+                self.data.set(x, y, value);
+            }
+        }
+        // Flashes
+        self.flash();
+        // Count flashes + Zero
+        let mut num_flashes = 0;
+        for y in 0..height {
+            for x in 0..width {
+                if let Some(score) = self.data.get(x, y) {
+                    if *score > 9 {
+                        num_flashes += 1;
+                        self.data.set(x, y, 0);
+                    }
+                }
+            }
+        }
+        self.display();
+        debug!("num_flashes = {}", num_flashes);
+        num_flashes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+";
+
+    #[test]
+    fn example() {
+        // Each part steps the octopuses on from where they start.
+        let solve = |part| Solution::parse_str(EXAMPLE).unwrap().solve(part).unwrap();
+        assert_eq!(solve(1), 1656);
+        assert_eq!(solve(2), 195);
+    }
+}
@@ -1,132 +0,0 @@
-use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-use utils::Matrix;
-
-pub fn load(filename: &str) -> Solution {
-    let file = File::open(filename).unwrap();
-
-    let reader = BufReader::new(file);
-
-    let mut solution = Solution::new();
-    for (y, line) in reader.lines().enumerate() {
-        let line = line.unwrap();
-        for (x, value) in line
-            .trim()
-            .chars()
-            .map(|v| v.to_string())
-            .map(|v| v.parse::<i64>().unwrap())
-            .enumerate()
-        {
-            solution.add(x.try_into().unwrap(), y.try_into().unwrap(), value);
-        }
-    }
-
-    solution
-}
-
-#[derive(Debug, Default)]
-pub struct Solution {
-    data: Matrix,
-    answer: i64,
-}
-
-impl Solution {
-    fn new() -> Self {
-        Self {
-            ..Default::default()
-        }
-    }
-
-    fn add(&mut self, x: isize, y: isize, value: i64) {
-        self.data.set(x, y, value)
-    }
-
-    fn get(&self, x: isize, y: isize) -> Option<i64> {
-        self.data.get(x, y).map(|v| v.to_owned())
-    }
-
-    fn flash(&mut self) {
-        let (xsize, ysize) = self.data.dimensions();
-
-        let mut flashed = HashSet::new();
-        let mut changed = true;
-        while changed {
-            changed = false;
-            for y in 0..=ysize {
-                for x in 0..=xsize {
-                    if let Some(score) = self.data.get(x, y) {
-                        if *score > 9 && !flashed.contains(&(x, y)) {
-                            changed = true;
-                            flashed.insert((x, y));
-                            for ystep in -1..=1 {
-                                for xstep in -1..=1 {
-                                    if let Some(value) =
-                                        self.get(x + xstep, y + ystep).map(|v| v + 1)
-                                    {
-                                        self.add(x + xstep, y + ystep, value)
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
-
-    fn display(&self) {
-        let (xsize, ysize) = self.data.dimensions();
-        for y in 0..=ysize {
-            for x in 0..=xsize {
-                if let Some(score) = self.data.get(x, y) {
-                    print!("{} ", score);
-                }
-            }
-            println!();
-        }
-    }
-
-    pub fn analyse(&mut self) {
-        self.answer = 0;
-        let (xsize, ysize) = self.data.dimensions();
-
-        for step in 1..=2000 {
-            println!("step {}", step);
-            // Increment all energy
-            for y in 0..=ysize {
-                for x in 0..=xsize {
-                    let value = self.data.get(x, y).unwrap() + 1;
-                    self.data.set(x, y, value);
-                }
-            }
-            // Flashes
-            self.flash();
-            // Count flashes + Zero
-            let mut num_flashes = 0;
-            for y in 0..=ysize {
-                for x in 0..=xsize {
-                    if let Some(score) = self.data.get(x, y) {
-                        if *score > 9 {
-                            num_flashes += 1;
-                            self.data.set(x, y, 0);
-                        }
-                    }
-                }
-            }
-            let total_cells = ((xsize + 1) * (ysize + 1)) as i64;
-            println!("num_flashes = {} vs {}", num_flashes, total_cells);
-            self.display();
-            if num_flashes == total_cells {
-                self.answer = step;
-                break;
-            }
-        }
-        println!("{}", self.answer);
-    }
-
-    pub fn answer(&self) -> i64 {
-        self.answer as i64
-    }
-}
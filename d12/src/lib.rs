@@ -0,0 +1,185 @@
+use anyhow::Result;
+use log::debug;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::io::BufRead;
+use utils::{search, ParseError, Solver};
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut solution = Solution::new();
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let (a, b) = line.trim().split_once('-').ok_or_else(|| {
+                ParseError::end_of_line(&line, "'-' between two caves").with_line(number + 1)
+            })?;
+            solution.add(a.to_string(), b.to_string());
+        }
+
+        Ok(solution)
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        Ok(self.count_paths(false))
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        Ok(self.count_paths(true))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Solution {
+    edges: HashMap<String, HashSet<String>>,
+}
+
+/// Where a path has got to, the small caves it has already been through, and
+/// whether it has used up its one repeat visit to a small cave.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Walk {
+    cave: String,
+    visited: BTreeSet<String>,
+    revisited: bool,
+}
+
+impl Walk {
+    /// A walk from `cave`, which may visit one small cave twice if
+    /// `may_revisit` is set.
+    fn new(cave: &str, may_revisit: bool) -> Self {
+        Self {
+            cave: cave.to_string(),
+            visited: BTreeSet::from([cave.to_string()]),
+            revisited: !may_revisit,
+        }
+    }
+
+    fn to(&self, cave: &str) -> Self {
+        let mut visited = self.visited.clone();
+        let mut revisited = self.revisited;
+        if !is_big(cave) && !visited.insert(cave.to_string()) {
+            revisited = true;
+        }
+        Self {
+            cave: cave.to_string(),
+            visited,
+            revisited,
+        }
+    }
+}
+
+fn is_big(cave: &str) -> bool {
+    cave.contains(char::is_uppercase)
+}
+
+impl Solution {
+    fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    fn add(&mut self, a: String, b: String) {
+        (*self.edges.entry(a.clone()).or_default()).insert(b.clone());
+        (*self.edges.entry(b).or_default()).insert(a);
+    }
+
+    fn next_walks(&self, walk: &Walk) -> Vec<Walk> {
+        self.edges
+            .get(&walk.cave)
+            .into_iter()
+            .flatten()
+            .filter(|next| {
+                is_big(next)
+                    || !walk.visited.contains(*next)
+                    || (!walk.revisited && *next != "start" && *next != "end")
+            })
+            .map(|next| walk.to(next))
+            .collect()
+    }
+
+    fn count_paths(&self, may_revisit: bool) -> i64 {
+        let paths = search::count_paths(
+            &Walk::new("start", may_revisit),
+            |walk| self.next_walks(walk),
+            |walk| walk.cave == "end",
+        ) as i64;
+
+        debug!("{}", paths);
+        paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end
+";
+
+    const LARGER_EXAMPLE: &str = "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc
+";
+
+    const LARGEST_EXAMPLE: &str = "\
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW
+";
+
+    #[test]
+    fn example() {
+        let mut solution = Solution::parse_str(EXAMPLE).unwrap();
+        assert_eq!(solution.part1().unwrap(), 10);
+        assert_eq!(solution.part2().unwrap(), 36);
+    }
+
+    #[test]
+    fn larger_example() {
+        let mut solution = Solution::parse_str(LARGER_EXAMPLE).unwrap();
+        assert_eq!(solution.part1().unwrap(), 19);
+        assert_eq!(solution.part2().unwrap(), 103);
+    }
+
+    #[test]
+    fn largest_example() {
+        let mut solution = Solution::parse_str(LARGEST_EXAMPLE).unwrap();
+        assert_eq!(solution.part1().unwrap(), 226);
+        assert_eq!(solution.part2().unwrap(), 3509);
+    }
+}
@@ -1,30 +1,50 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::num::ParseIntError;
+use anyhow::{Context, Result};
+use log::{debug, info};
+use std::io::BufRead;
 use std::str::FromStr;
 
-use utils::Matrix;
+use utils::{parse_at, Matrix, ParseError, Solver};
 
-pub fn load(filename: &str) -> Solution {
-    let file = File::open(filename).unwrap();
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
 
-    let reader = BufReader::new(file);
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut solution = Solution::new();
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = Line::from_str(&line).map_err(|e| e.with_line(number + 1))?;
+            solution.add(line);
+        }
+
+        Ok(solution)
+    }
 
-    let mut solution = Solution::new();
-    for line in reader.lines() {
-        let line = line.unwrap();
-        let line = Line::from_str(&line);
-        solution.add(line.unwrap());
+    fn part1(&mut self) -> Result<Self::Answer> {
+        let (direction, position) = *self.folds.first().context("There are no folds")?;
+        self.fold(direction, position);
+        let count = self.count() as i64;
+        debug!("{}", count);
+        Ok(count)
     }
 
-    solution
+    fn part2(&mut self) -> Result<Self::Answer> {
+        for (direction, position) in self.folds.clone() {
+            self.fold(direction, position);
+        }
+        // The code is only shown in the log
+        self.display();
+        Ok(self.count() as i64)
+    }
 }
 
 #[derive(Debug, Default)]
 pub struct Solution {
     points: Matrix,
     folds: Vec<(Direction, isize)>,
-    answer: i64,
 }
 
 impl Solution {
@@ -85,28 +105,17 @@ impl Solution {
     fn display(&self) {
         let (xsize, ysize) = self.points.dimensions();
         for y in 0..=ysize {
+            let mut row = String::new();
             for x in 0..=xsize {
                 let c = match self.points.get(x, y) {
                     Some(_) => '#',
                     _ => ' ',
                 };
-                print!("{}", c);
+                row.push(c);
             }
-            println!();
+            info!("{}", row);
         }
     }
-
-    pub fn analyse(&mut self) {
-        for (direction, position) in self.folds.clone() {
-            self.fold(direction, position);
-        }
-        self.answer = self.count() as i64;
-        self.display();
-    }
-
-    pub fn answer(&self) -> i64 {
-        self.answer as i64
-    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -121,30 +130,34 @@ enum Line {
 }
 
 impl FromStr for Line {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        println!("line = {}", s);
-        if s.starts_with("fold along") {
-            println!("fold");
-            let fold = s.to_owned().replace("fold along ", "");
-            let mut fold = fold.split('=');
-            let dirn = fold.next().unwrap();
-            let posn = fold.next().unwrap().parse::<isize>()?;
-            match dirn {
-                "x" => Ok(Self::Fold(Direction::X, posn)),
-                "y" => Ok(Self::Fold(Direction::Y, posn)),
-                _ => dirn.parse::<i32>().map(|_v| Self::None),
-            }
+        debug!("line = {}", s);
+        if let Some(fold) = s.strip_prefix("fold along ") {
+            debug!("fold");
+            let (dirn, posn) = fold
+                .trim_end()
+                .split_once('=')
+                .ok_or_else(|| ParseError::end_of_line(s, "'='"))?;
+            let dirn = match dirn {
+                "x" => Direction::X,
+                "y" => Direction::Y,
+                _ => return Err(ParseError::at(s, dirn, "x or y")),
+            };
+            Ok(Self::Fold(dirn, parse_at(s, posn, "a position")?))
         } else if s.trim().is_empty() {
-            println!("none");
+            debug!("none");
             Ok(Self::None)
         } else {
-            println!("dot");
-            let coords: Vec<&str> = s.trim().split(',').collect();
-
-            let x_fromstr = coords[0].parse::<i32>()?;
-            let y_fromstr = coords[1].parse::<i32>()?;
+            debug!("dot");
+            let (x, y) = s
+                .trim()
+                .split_once(',')
+                .ok_or_else(|| ParseError::end_of_line(s, "','"))?;
+
+            let x_fromstr = parse_at(s, x, "a number")?;
+            let y_fromstr = parse_at(s, y, "a number")?;
 
             Ok(Self::Point(x_fromstr, y_fromstr))
         }
@@ -0,0 +1,11 @@
+use anyhow::Result;
+use d13::Solution;
+use utils::Solver;
+
+fn main() -> Result<()> {
+    let (part, mut solution) = Solution::from_args(13)?;
+    println!("solution: {:?}", solution);
+    println!("answer is {}", solution.solve(part)?);
+
+    Ok(())
+}
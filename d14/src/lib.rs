@@ -1,27 +1,39 @@
+use anyhow::Result;
+use log::debug;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::num::ParseIntError;
+use std::io::BufRead;
 use std::str::FromStr;
+use utils::{ParseError, Solver};
 
-pub fn load(filename: &str) -> Solution {
-    let file = File::open(filename).unwrap();
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
 
-    let reader = BufReader::new(file);
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut solution = Solution::new();
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = Line::from_str(&line).map_err(|e| e.with_line(number + 1))?;
+            solution.add(line);
+        }
+
+        Ok(solution)
+    }
 
-    let mut solution = Solution::new();
-    for line in reader.lines() {
-        let line = line.unwrap();
-        let line = Line::from_str(&line);
-        solution.add(line.unwrap());
+    fn part1(&mut self) -> Result<Self::Answer> {
+        Ok(self.polymerise(10))
     }
 
-    solution
+    fn part2(&mut self) -> Result<Self::Answer> {
+        Ok(self.polymerise(40))
+    }
 }
 
 #[derive(Debug, Default)]
 pub struct Solution {
-    answer: i64,
     template: HashMap<String, i64>,
     first: String,
     last: String,
@@ -57,9 +69,11 @@ impl Solution {
         result
     }
 
-    pub fn analyse(&mut self) {
+    /// The difference between the most and least common elements, after
+    /// `steps` steps of pair insertion.
+    fn polymerise(&self, steps: u32) -> i64 {
         let mut template = self.template.clone();
-        for _i in 1..=40 {
+        for _i in 1..=steps {
             template = template
                 .into_iter()
                 .map(|(k, v)| match self.rules.iter().find(|(s, _t)| *s == k) {
@@ -84,7 +98,7 @@ impl Solution {
                     acc
                 });
         }
-        println!("template: {:?}", template);
+        debug!("template: {:?}", template);
         let mut freq = template.iter().fold(HashMap::new(), |mut acc, (s, count)| {
             for c in s.chars() {
                 *acc.entry(c).or_insert(0i64) += count;
@@ -97,7 +111,7 @@ impl Solution {
         for c in self.last.chars() {
             *freq.entry(c).or_insert(0i64) += 1;
         }
-        println!("freq {:?}", freq);
+        debug!("freq {:?}", freq);
         let (min, max) = freq.into_iter().fold((-1, 1), |mut acc, (_k, v)| {
             if acc.0 == -1 || acc.0 > v {
                 acc.0 = v;
@@ -109,12 +123,8 @@ impl Solution {
         });
         let max = max / 2;
         let min = min / 2;
-        println!("{} {}", min, max);
-        self.answer = max - min;
-    }
-
-    pub fn answer(&self) -> i64 {
-        self.answer as i64
+        debug!("{} {}", min, max);
+        max - min
     }
 }
 
@@ -125,27 +135,30 @@ enum Line {
 }
 
 impl FromStr for Line {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.contains("->") {
-            let mut rule = s.split("->").map(|v| v.trim());
-            let source = rule.next().unwrap().to_string();
-            let target = rule.next().unwrap().to_string();
-            Ok(Self::Rule(source, target))
+        if let Some((source, target)) = s.split_once("->") {
+            let (source, target) = (source.trim(), target.trim());
+            if source.chars().count() != 2 {
+                return Err(ParseError::at(s, source, "a pair of elements"));
+            }
+            if target.chars().count() != 1 {
+                return Err(ParseError::at(s, target, "a single element"));
+            }
+            Ok(Self::Rule(source.to_string(), target.to_string()))
         } else if s.trim().is_empty() {
             Ok(Self::None)
         } else {
             let template = s.trim().to_string();
-            let first = template[0..0].to_string();
+            let first = template[0..1].to_string();
             let last = template[template.len() - 1..].to_string();
             let template = template
                 .chars()
                 .collect::<Vec<char>>()
                 .windows(2)
-                .map(|c| {
-                    println!("{:?}", c);
-                    c
+                .inspect(|&c| {
+                    debug!("{:?}", c);
                 })
                 .map(|c| c.iter().collect::<String>())
                 .fold(HashMap::new(), |mut acc, v| {
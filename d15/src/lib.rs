@@ -0,0 +1,110 @@
+use anyhow::{Context, Result};
+use log::debug;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use utils::{search, Grid, Neighbourhood, Solver};
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
+
+    fn parse<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        let grid = Grid::<u8>::from_str(&input)?;
+        let solution = Solution::new(grid.map(|v| *v as i64));
+        solution.display();
+        Ok(solution)
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        self.lowest_risk()
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        self.expand(5, 5);
+        self.display();
+        self.lowest_risk()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Solution {
+    data: Grid<i64>,
+    xsize: isize,
+    ysize: isize,
+}
+
+impl Solution {
+    fn new(data: Grid<i64>) -> Self {
+        Self {
+            xsize: data.width() as isize - 1,
+            ysize: data.height() as isize - 1,
+            data,
+        }
+    }
+
+    fn expand(&mut self, xfactor: usize, yfactor: usize) {
+        let (width, height) = (self.data.width(), self.data.height());
+        self.data = Grid::from_fn(width * xfactor, height * yfactor, |x, y| {
+            let v = self.data[(x % width, y % height)] + (x / width + y / height) as i64;
+            // Risk levels above 9 wrap back around to 1
+            (v - 1) % 9 + 1
+        });
+        self.xsize = self.data.width() as isize - 1;
+        self.ysize = self.data.height() as isize - 1;
+        debug!("({}, {})", self.xsize, self.ysize);
+    }
+
+    fn display(&self) {
+        debug!("\n{}", self.data);
+    }
+
+    /// The total risk of the safest route from the top left to the bottom right.
+    fn lowest_risk(&self) -> Result<i64> {
+        let goal = (self.xsize, self.ysize);
+        let route = search::dijkstra(
+            &(0, 0),
+            |&(x, y)| {
+                self.data
+                    .neighbours(x, y, Neighbourhood::Orthogonal)
+                    .map(|(point, risk)| (point, *risk))
+                    .collect::<Vec<_>>()
+            },
+            |point| *point == goal,
+        );
+        debug!("route: {:?}", route);
+        route
+            .map(|(_, risk)| risk)
+            .context("There is no route to the bottom right")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+";
+
+    #[test]
+    fn example() {
+        let mut solution = Solution::parse_str(EXAMPLE).unwrap();
+        assert_eq!(solution.part1().unwrap(), 40);
+        assert_eq!(solution.part2().unwrap(), 315);
+    }
+}
@@ -0,0 +1,1016 @@
+//! The Buoyancy Interchange Transmission System.
+//!
+//! A transmission is a single packet, written as hexadecimal. Every packet
+//! starts with a 3 bit version and a 3 bit type id. Type 4 is a literal,
+//! whose value follows in 5 bit groups: a flag saying whether another group
+//! follows, then 4 bits of the value. Every other type is an operator over
+//! the sub-packets that follow, preceded either by their total length in bits
+//! (15 bits) or by their number (11 bits), as chosen by a 1 bit length type.
+//!
+//! Literals may have any number of groups. Their values are only limited
+//! when evaluated: [`Packet::checked_value`] works in 64 bits, and, with the
+//! `bigint` feature, [`Packet::big_value`] in as many as it takes.
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
+use std::error::Error;
+use std::fmt;
+
+/// A decoded packet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub version: u8,
+    pub body: Body,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Body {
+    Literal(Literal),
+    Operator {
+        operator: Operator,
+        length: LengthType,
+        packets: Vec<Packet>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operator {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqualTo,
+}
+
+/// The value of a literal, kept as its 4 bit groups, most significant first,
+/// so that it can be any size.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Literal {
+    /// Never empty, and without leading zeros, unless the value is zero.
+    groups: Vec<u8>,
+}
+
+/// How an operator says where its sub-packets end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LengthType {
+    /// The total length of the sub-packets, in bits.
+    Bits,
+    /// The number of sub-packets.
+    Packets,
+}
+
+const VERSION_BITS: u32 = 3;
+const TYPE_BITS: u32 = 3;
+const LITERAL_TYPE: u64 = 4;
+const GROUP_BITS: u32 = 4;
+const LENGTH_BITS: u32 = 15;
+const COUNT_BITS: u32 = 11;
+
+/// The most packets a transmission may nest inside one another. Decoding,
+/// evaluating and printing packets all recurse into their sub-packets, so
+/// this keeps them well within the stack.
+pub const MAX_DEPTH: usize = 512;
+
+impl Literal {
+    /// The literal with these 4 bit groups, most significant first.
+    pub fn from_groups(groups: impl IntoIterator<Item = u8>) -> Self {
+        let mut groups = groups
+            .into_iter()
+            .skip_while(|group| *group == 0)
+            .collect::<Vec<_>>();
+        if groups.is_empty() {
+            groups.push(0);
+        }
+        Self { groups }
+    }
+
+    /// The 4 bit groups of the value, most significant first.
+    pub fn groups(&self) -> &[u8] {
+        &self.groups
+    }
+
+    /// The value, if it fits into 64 bits.
+    pub fn to_u64(&self) -> Option<u64> {
+        (self.groups.len() <= (u64::BITS / GROUP_BITS) as usize).then(|| {
+            self.groups
+                .iter()
+                .fold(0, |value, group| value << GROUP_BITS | *group as u64)
+        })
+    }
+
+    #[cfg(feature = "bigint")]
+    pub fn to_big(&self) -> BigUint {
+        BigUint::from_radix_be(&self.groups, 1 << GROUP_BITS).unwrap()
+    }
+}
+
+impl From<u64> for Literal {
+    fn from(value: u64) -> Self {
+        Self::from_groups(
+            (0..u64::BITS / GROUP_BITS)
+                .rev()
+                .map(|group| (value >> (group * GROUP_BITS) & 0xf) as u8),
+        )
+    }
+}
+
+/// Literals print in decimal, however large.
+impl fmt::Display for Literal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(value) = self.to_u64() {
+            return write!(f, "{}", value);
+        }
+        // Long division by 10 gives the digits, least significant first.
+        let mut groups = self.groups.clone();
+        let mut digits = Vec::new();
+        while !groups.is_empty() {
+            let mut remainder = 0;
+            for group in groups.iter_mut() {
+                let value = remainder << GROUP_BITS | *group;
+                *group = value / 10;
+                remainder = value % 10;
+            }
+            digits.push(b'0' + remainder);
+            let zeros = groups.iter().take_while(|group| **group == 0).count();
+            groups.drain(..zeros);
+        }
+        digits.reverse();
+        f.write_str(std::str::from_utf8(&digits).unwrap())
+    }
+}
+
+impl Operator {
+    fn from_type(type_id: u64) -> Option<Self> {
+        use Operator::*;
+        match type_id {
+            0 => Some(Sum),
+            1 => Some(Product),
+            2 => Some(Minimum),
+            3 => Some(Maximum),
+            5 => Some(GreaterThan),
+            6 => Some(LessThan),
+            7 => Some(EqualTo),
+            _ => None,
+        }
+    }
+
+    fn type_id(self) -> u64 {
+        use Operator::*;
+        match self {
+            Sum => 0,
+            Product => 1,
+            Minimum => 2,
+            Maximum => 3,
+            GreaterThan => 5,
+            LessThan => 6,
+            EqualTo => 7,
+        }
+    }
+
+    /// The symbol the S-expression form uses for this operator.
+    pub fn symbol(self) -> &'static str {
+        use Operator::*;
+        match self {
+            Sum => "+",
+            Product => "*",
+            Minimum => "min",
+            Maximum => "max",
+            GreaterThan => ">",
+            LessThan => "<",
+            EqualTo => "=",
+        }
+    }
+
+    /// Whether this operator can be applied to `count` sub-packets:
+    /// comparisons take exactly two, everything else at least one.
+    pub fn accepts(self, count: usize) -> bool {
+        use Operator::*;
+        match self {
+            GreaterThan | LessThan | EqualTo => count == 2,
+            Sum | Product | Minimum | Maximum => count >= 1,
+        }
+    }
+}
+
+impl Packet {
+    pub fn literal(version: u8, value: u64) -> Self {
+        Self {
+            version,
+            body: Body::Literal(value.into()),
+        }
+    }
+
+    pub fn operator(
+        version: u8,
+        operator: Operator,
+        length: LengthType,
+        packets: Vec<Packet>,
+    ) -> Self {
+        Self {
+            version,
+            body: Body::Operator {
+                operator,
+                length,
+                packets,
+            },
+        }
+    }
+
+    /// The packets directly inside this one.
+    pub fn packets(&self) -> &[Packet] {
+        match &self.body {
+            Body::Literal(_) => &[],
+            Body::Operator { packets, .. } => packets,
+        }
+    }
+
+    /// The sum of the version numbers of this packet and all it contains.
+    pub fn version_total(&self) -> u64 {
+        self.version as u64
+            + self
+                .packets()
+                .iter()
+                .map(|packet| packet.version_total())
+                .sum::<u64>()
+    }
+
+    /// The value of the expression this packet represents, or an error
+    /// naming the packet whose result does not fit into 64 bits.
+    pub fn checked_value(&self) -> Result<u64, EvalError> {
+        self.checked_value_at(&mut Vec::new())
+    }
+
+    fn checked_value_at(&self, path: &mut Vec<usize>) -> Result<u64, EvalError> {
+        use Operator::*;
+        let operator = match &self.body {
+            Body::Literal(literal) => {
+                return literal
+                    .to_u64()
+                    .ok_or_else(|| EvalError::LiteralTooLarge { path: path.clone() })
+            }
+            Body::Operator { operator, .. } => *operator,
+        };
+        let mut values = Vec::new();
+        for (index, packet) in self.packets().iter().enumerate() {
+            path.push(index);
+            values.push(packet.checked_value_at(path)?);
+            path.pop();
+        }
+        let overflow = || EvalError::Overflow {
+            path: path.clone(),
+            operator,
+        };
+        Ok(match operator {
+            Sum => values
+                .into_iter()
+                .try_fold(0_u64, |total, value| total.checked_add(value))
+                .ok_or_else(overflow)?,
+            // A zero anywhere keeps the product in range, whatever comes before it.
+            Product if values.contains(&0) => 0,
+            Product => values
+                .into_iter()
+                .try_fold(1_u64, |total, value| total.checked_mul(value))
+                .ok_or_else(overflow)?,
+            _ => select(operator, values),
+        })
+    }
+
+    /// The value of the expression this packet represents, however large.
+    #[cfg(feature = "bigint")]
+    pub fn big_value(&self) -> BigUint {
+        use Operator::*;
+        let operator = match &self.body {
+            Body::Literal(literal) => return literal.to_big(),
+            Body::Operator { operator, .. } => *operator,
+        };
+        let values = self.packets().iter().map(|packet| packet.big_value());
+        match operator {
+            Sum => values.sum(),
+            Product => values.product(),
+            _ => select(operator, values.collect()),
+        }
+    }
+
+    /// Decode the packet held in `hex`. Anything after the packet must be
+    /// zero padding.
+    pub fn from_hex(hex: &str) -> Result<Self, DecodeError> {
+        let bytes = hex_to_bytes(hex)?;
+        let mut reader = BitReader::new(&bytes, 4 * hex.len());
+        let packet = Self::read(&mut reader)?;
+        reader.expect_padding()?;
+        Ok(packet)
+    }
+
+    /// Decode the next packet from `reader`.
+    pub fn read(reader: &mut BitReader) -> Result<Self, DecodeError> {
+        Self::read_nested(reader, 1)
+    }
+
+    /// Decode the next packet from `reader`, which is inside `depth - 1`
+    /// others.
+    fn read_nested(reader: &mut BitReader, depth: usize) -> Result<Self, DecodeError> {
+        let start = reader.position();
+        if depth > MAX_DEPTH {
+            return Err(DecodeError::TooDeep { position: start });
+        }
+        let version = reader.read(VERSION_BITS, "the packet version")? as u8;
+        let type_id = reader.read(TYPE_BITS, "the packet type")?;
+        let operator = match Operator::from_type(type_id) {
+            Some(operator) => operator,
+            None => {
+                return Ok(Self {
+                    version,
+                    body: Body::Literal(Self::read_literal(reader)?),
+                })
+            }
+        };
+
+        let mut packets = Vec::new();
+        let length = if reader.read(1, "the length type")? == 0 {
+            let length = reader.read(LENGTH_BITS, "the sub-packet length")? as usize;
+            let end = reader.position() + length;
+            while reader.position() < end {
+                packets.push(Self::read_nested(reader, depth + 1)?);
+            }
+            if reader.position() > end {
+                return Err(DecodeError::Overrun {
+                    position: start,
+                    length,
+                });
+            }
+            LengthType::Bits
+        } else {
+            let count = reader.read(COUNT_BITS, "the sub-packet count")?;
+            for _ in 0..count {
+                packets.push(Self::read_nested(reader, depth + 1)?);
+            }
+            LengthType::Packets
+        };
+
+        if !operator.accepts(packets.len()) {
+            return Err(DecodeError::Operands {
+                position: start,
+                operator,
+                count: packets.len(),
+            });
+        }
+        Ok(Self::operator(version, operator, length, packets))
+    }
+
+    fn read_literal(reader: &mut BitReader) -> Result<Literal, DecodeError> {
+        let mut groups = Vec::new();
+        loop {
+            let more = reader.read(1, "a literal group")?;
+            groups.push(reader.read(GROUP_BITS, "a literal group")? as u8);
+            if more == 0 {
+                return Ok(Literal::from_groups(groups));
+            }
+        }
+    }
+
+    /// Encode this packet, padded with zeros to a whole number of bytes, as
+    /// upper case hexadecimal.
+    pub fn to_hex(&self) -> Result<String, EncodeError> {
+        let mut writer = BitWriter::default();
+        self.write(&mut writer)?;
+        Ok(writer
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect())
+    }
+
+    /// Encode this packet onto the end of `writer`.
+    pub fn write(&self, writer: &mut BitWriter) -> Result<(), EncodeError> {
+        if self.version as u64 >= 1 << VERSION_BITS {
+            return Err(EncodeError::Version(self.version));
+        }
+        writer.write(self.version as u64, VERSION_BITS);
+        let (operator, length, packets) = match &self.body {
+            Body::Literal(literal) => {
+                writer.write(LITERAL_TYPE, TYPE_BITS);
+                Self::write_literal(literal, writer);
+                return Ok(());
+            }
+            Body::Operator {
+                operator,
+                length,
+                packets,
+            } => (*operator, *length, packets),
+        };
+        if !operator.accepts(packets.len()) {
+            return Err(EncodeError::Operands {
+                operator,
+                count: packets.len(),
+            });
+        }
+        writer.write(operator.type_id(), TYPE_BITS);
+
+        match length {
+            LengthType::Bits => {
+                let mut inner = BitWriter::default();
+                for packet in packets {
+                    packet.write(&mut inner)?;
+                }
+                if inner.len() >= 1 << LENGTH_BITS {
+                    return Err(EncodeError::Length(inner.len()));
+                }
+                writer.write(0, 1);
+                writer.write(inner.len() as u64, LENGTH_BITS);
+                writer.append(&inner);
+            }
+            LengthType::Packets => {
+                if packets.len() >= 1 << COUNT_BITS {
+                    return Err(EncodeError::Count(packets.len()));
+                }
+                writer.write(1, 1);
+                writer.write(packets.len() as u64, COUNT_BITS);
+                for packet in packets {
+                    packet.write(writer)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn write_literal(literal: &Literal, writer: &mut BitWriter) {
+        let groups = literal.groups();
+        for (index, group) in groups.iter().enumerate() {
+            writer.write((index + 1 < groups.len()) as u64, 1);
+            writer.write(*group as u64, GROUP_BITS);
+        }
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let (operator, packets) = match &self.body {
+            Body::Literal(literal) => return write!(f, "{}", literal),
+            Body::Operator {
+                operator, packets, ..
+            } => (operator, packets),
+        };
+        write!(f, "({}", operator.symbol())?;
+        for packet in packets {
+            if f.alternate() {
+                write!(f, "\n{:width$}", "", width = 2 * (depth + 1))?;
+            } else {
+                write!(f, " ")?;
+            }
+            packet.fmt_indented(f, depth + 1)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Packets print as S-expressions, such as `(+ 1 (* 2 3))`. The alternate
+/// form, `{:#}`, puts each sub-packet on its own, indented, line.
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+/// Apply one of the operators which picks, or compares, its operands.
+/// Decoding makes sure every operator has enough operands.
+fn select<T: Ord + From<u8>>(operator: Operator, values: Vec<T>) -> T {
+    use Operator::*;
+    let mut values = values.into_iter();
+    let flag = |condition: bool| T::from(condition as u8);
+    match operator {
+        Minimum => values.min().unwrap_or_else(|| T::from(0)),
+        Maximum => values.max().unwrap_or_else(|| T::from(0)),
+        GreaterThan => flag(values.next() > values.next()),
+        LessThan => flag(values.next() < values.next()),
+        EqualTo => flag(values.next() == values.next()),
+        Sum | Product => unreachable!("{:?} is arithmetic", operator),
+    }
+}
+
+fn hex_to_bytes(hex: &str) -> Result<Vec<u8>, DecodeError> {
+    let mut bytes = vec![0; hex.len().div_ceil(2)];
+    for (index, c) in hex.char_indices() {
+        let nibble = c
+            .to_digit(16)
+            .ok_or(DecodeError::InvalidHex { index, found: c })?;
+        bytes[index / 2] |= (nibble as u8) << if index % 2 == 0 { 4 } else { 0 };
+    }
+    Ok(bytes)
+}
+
+/// Reads big-endian bit fields from a slice of bytes.
+#[derive(Debug)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    /// Bits past this one are not part of the input.
+    end: usize,
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Read the first `len` bits of `bytes`.
+    pub fn new(bytes: &'a [u8], len: usize) -> Self {
+        Self {
+            bytes,
+            end: len.min(8 * bytes.len()),
+            position: 0,
+        }
+    }
+
+    /// How many bits have been read.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// How many bits are left to read.
+    pub fn remaining(&self) -> usize {
+        self.end - self.position
+    }
+
+    /// Read the next `bits` bits, at most 64, of `what` as a number.
+    pub fn read(&mut self, bits: u32, what: &'static str) -> Result<u64, DecodeError> {
+        assert!(bits <= u64::BITS);
+        if self.remaining() < bits as usize {
+            return Err(DecodeError::Truncated {
+                position: self.position,
+                reading: what,
+                wanted: bits,
+                remaining: self.remaining(),
+            });
+        }
+        let mut value = 0;
+        for _ in 0..bits {
+            let byte = self.bytes[self.position / 8];
+            let bit = byte >> (7 - self.position % 8) & 1;
+            value = value << 1 | bit as u64;
+            self.position += 1;
+        }
+        Ok(value)
+    }
+
+    /// Check that only zeros are left to read.
+    fn expect_padding(&mut self) -> Result<(), DecodeError> {
+        let position = self.position;
+        while self.remaining() > 0 {
+            if self.read(1, "padding")? != 0 {
+                return Err(DecodeError::TrailingData { position });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builds a sequence of big-endian bit fields.
+#[derive(Debug, Default)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitWriter {
+    /// How many bits have been written.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Append the low `bits` bits of `value`.
+    pub fn write(&mut self, value: u64, bits: u32) {
+        for bit in (0..bits).rev() {
+            self.push(value >> bit & 1 == 1);
+        }
+    }
+
+    /// Append everything written to `other`.
+    pub fn append(&mut self, other: &BitWriter) {
+        for position in 0..other.len {
+            self.push(other.bytes[position / 8] >> (7 - position % 8) & 1 == 1);
+        }
+    }
+
+    fn push(&mut self, bit: bool) {
+        if self.len.is_multiple_of(8) {
+            self.bytes.push(0);
+        }
+        if bit {
+            self.bytes[self.len / 8] |= 1 << (7 - self.len % 8);
+        }
+        self.len += 1;
+    }
+
+    /// The bits written, padded with zeros to a whole number of bytes.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// Why a transmission could not be decoded. Positions count bits from the
+/// start of the transmission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The character at (0-based) `index` is not a hexadecimal digit.
+    InvalidHex { index: usize, found: char },
+    /// The transmission ended while reading a field.
+    Truncated {
+        position: usize,
+        reading: &'static str,
+        wanted: u32,
+        remaining: usize,
+    },
+    /// An operator's sub-packets ran past the length it gave for them.
+    Overrun { position: usize, length: usize },
+    /// An operator has the wrong number of sub-packets.
+    Operands {
+        position: usize,
+        operator: Operator,
+        count: usize,
+    },
+    /// Packets are nested more than [`MAX_DEPTH`] deep.
+    TooDeep { position: usize },
+    /// Something other than zero padding follows the packet.
+    TrailingData { position: usize },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use DecodeError::*;
+        match self {
+            InvalidHex { index, found } => {
+                write!(f, "{:?} at index {} is not a hexadecimal digit", found, index)
+            }
+            Truncated {
+                position,
+                reading,
+                wanted,
+                remaining,
+            } => write!(
+                f,
+                "bit {}: the transmission ends while reading {}, which needs {} bits but only {} remain",
+                position, reading, wanted, remaining
+            ),
+            Overrun { position, length } => write!(
+                f,
+                "bit {}: the sub-packets run past their length of {} bits",
+                position, length
+            ),
+            Operands {
+                position,
+                operator,
+                count,
+            } => write!(
+                f,
+                "bit {}: {:?} cannot take {} sub-packets",
+                position, operator, count
+            ),
+            TooDeep { position } => write!(
+                f,
+                "bit {}: packets are nested more than {} deep",
+                position, MAX_DEPTH
+            ),
+            TrailingData { position } => {
+                write!(f, "bit {}: the packet is followed by more data", position)
+            }
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Why a packet could not be encoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    /// Versions must fit into 3 bits.
+    Version(u8),
+    /// Sub-packets counted in bits must fit into 15 bits.
+    Length(usize),
+    /// Sub-packets counted as packets must number fewer than 2048.
+    Count(usize),
+    /// An operator has the wrong number of sub-packets.
+    Operands { operator: Operator, count: usize },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use EncodeError::*;
+        match self {
+            Version(version) => write!(f, "version {} does not fit in 3 bits", version),
+            Length(length) => write!(
+                f,
+                "sub-packets of {} bits do not fit in a 15 bit length",
+                length
+            ),
+            Count(count) => write!(f, "{} sub-packets do not fit in an 11 bit count", count),
+            Operands { operator, count } => {
+                write!(f, "{:?} cannot take {} sub-packets", operator, count)
+            }
+        }
+    }
+}
+
+impl Error for EncodeError {}
+
+/// Why a packet could not be evaluated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalError {
+    /// The result of `operator` does not fit into 64 bits. The packet is
+    /// found by following `path`, the (0-based) index of each sub-packet in
+    /// turn, from the outermost packet.
+    Overflow {
+        path: Vec<usize>,
+        operator: Operator,
+    },
+    /// The literal found by following `path` does not fit into 64 bits.
+    LiteralTooLarge { path: Vec<usize> },
+}
+
+/// The packet found by following `path`, in words.
+fn describe(path: &[usize]) -> String {
+    if path.is_empty() {
+        return "the outermost packet".to_string();
+    }
+    let path = path
+        .iter()
+        .map(|index| index.to_string())
+        .collect::<Vec<_>>()
+        .join(".");
+    format!("sub-packet {}", path)
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::Overflow { path, operator } => write!(
+                f,
+                "the {:?} of {} overflows 64 bits",
+                operator,
+                describe(path)
+            ),
+            EvalError::LiteralTooLarge { path } => write!(
+                f,
+                "{} is a literal which does not fit in 64 bits",
+                describe(path)
+            ),
+        }
+    }
+}
+
+impl Error for EvalError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compile::compile;
+
+    #[test]
+    fn bit_reader() {
+        let bytes = [0b1010_0110, 0b0100_0000];
+        let mut reader = BitReader::new(&bytes, 12);
+        assert_eq!(reader.read(3, "a").unwrap(), 0b101);
+        assert_eq!(reader.read(6, "b").unwrap(), 0b001100);
+        assert_eq!(reader.remaining(), 3);
+        assert_eq!(
+            reader.read(4, "c"),
+            Err(DecodeError::Truncated {
+                position: 9,
+                reading: "c",
+                wanted: 4,
+                remaining: 3
+            })
+        );
+        assert_eq!(reader.read(3, "c").unwrap(), 0b100);
+    }
+
+    #[test]
+    fn literal() {
+        assert_eq!(
+            Packet::from_hex("D2FE28").unwrap(),
+            Packet::literal(6, 2021)
+        );
+    }
+
+    #[test]
+    fn operators() {
+        assert_eq!(
+            Packet::from_hex("38006F45291200").unwrap(),
+            Packet::operator(
+                1,
+                Operator::LessThan,
+                LengthType::Bits,
+                vec![Packet::literal(6, 10), Packet::literal(2, 20)]
+            )
+        );
+        assert_eq!(
+            Packet::from_hex("EE00D40C823060").unwrap(),
+            Packet::operator(
+                7,
+                Operator::Maximum,
+                LengthType::Packets,
+                vec![
+                    Packet::literal(2, 1),
+                    Packet::literal(4, 2),
+                    Packet::literal(1, 3)
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        assert_eq!(
+            Packet::from_hex("D2FE"),
+            Err(DecodeError::Truncated {
+                position: 16,
+                reading: "a literal group",
+                wanted: 1,
+                remaining: 0
+            })
+        );
+        assert!(matches!(
+            Packet::from_hex("38006F4529"),
+            Err(DecodeError::Truncated { .. })
+        ));
+        assert!(matches!(
+            Packet::from_hex("EE00D40C82"),
+            Err(DecodeError::Truncated { position: 40, .. })
+        ));
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            Packet::from_hex("D2FG28"),
+            Err(DecodeError::InvalidHex {
+                index: 3,
+                found: 'G'
+            })
+        );
+        assert_eq!(
+            Packet::from_hex("D2FE2F"),
+            Err(DecodeError::TrailingData { position: 21 })
+        );
+        // A comparison over three literals
+        assert_eq!(
+            Packet::from_hex("3A00D40C823060"),
+            Err(DecodeError::Operands {
+                position: 0,
+                operator: Operator::LessThan,
+                count: 3
+            })
+        );
+    }
+
+    /// A literal 1 inside `depth - 1` sums of one sub-packet each.
+    fn nested(depth: usize) -> String {
+        let mut writer = BitWriter::default();
+        for _ in 1..depth {
+            writer.write(0, VERSION_BITS + TYPE_BITS);
+            writer.write(1, 1);
+            writer.write(1, COUNT_BITS);
+        }
+        writer.write(LITERAL_TYPE, VERSION_BITS + TYPE_BITS);
+        writer.write(1, 1 + GROUP_BITS);
+        writer
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect()
+    }
+
+    #[test]
+    fn too_deep() {
+        let packet = Packet::from_hex(&nested(MAX_DEPTH)).unwrap();
+        assert_eq!(packet.checked_value(), Ok(1));
+        #[cfg(feature = "bigint")]
+        assert_eq!(packet.big_value(), BigUint::from(1_u8));
+        assert_eq!(
+            Packet::from_hex(&nested(MAX_DEPTH + 1)),
+            Err(DecodeError::TooDeep {
+                position: 18 * MAX_DEPTH
+            })
+        );
+        // Far deeper than the stack could take
+        let error = Packet::from_hex(&nested(1_000_000)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "bit {}: packets are nested more than {} deep",
+                18 * MAX_DEPTH,
+                MAX_DEPTH
+            )
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for hex in [
+            "D2FE28",
+            "38006F45291200",
+            "EE00D40C823060",
+            "8A004A801A8002F478",
+            "620080001611562C8802118E34",
+            "C0015000016115A2E0802F182340",
+            "A0016C880162017C3686B18A3D4780",
+            "9C0141080250320F1802104A08",
+        ] {
+            let packet = Packet::from_hex(hex).unwrap();
+            assert_eq!(packet.to_hex().unwrap(), hex);
+        }
+    }
+
+    #[test]
+    fn encode_errors() {
+        assert_eq!(Packet::literal(8, 1).to_hex(), Err(EncodeError::Version(8)));
+        assert_eq!(
+            Packet::operator(0, Operator::EqualTo, LengthType::Bits, vec![]).to_hex(),
+            Err(EncodeError::Operands {
+                operator: Operator::EqualTo,
+                count: 0
+            })
+        );
+    }
+
+    #[test]
+    fn s_expression() {
+        let packet = Packet::from_hex("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(packet.to_string(), "(= (+ 1 3) (* 2 2))");
+        assert_eq!(
+            format!("{:#}", packet),
+            "(=\n  (+\n    1\n    3)\n  (*\n    2\n    2))"
+        );
+    }
+
+    #[test]
+    fn checked_value() {
+        let value = |source| compile(source).unwrap().checked_value();
+        assert_eq!(value("max(3, 7*2) > 9"), Ok(1));
+        assert_eq!(value("18446744073709551615 + 0"), Ok(u64::MAX));
+        assert_eq!(value("4294967296 * 4294967296 * 0"), Ok(0));
+        assert_eq!(
+            value("4294967296 * 4294967296"),
+            Err(EvalError::Overflow {
+                path: vec![],
+                operator: Operator::Product
+            })
+        );
+        let error = value("max(1, 2 * (18446744073709551615 + 1))").unwrap_err();
+        assert_eq!(
+            error,
+            EvalError::Overflow {
+                path: vec![1, 1],
+                operator: Operator::Sum
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "the Sum of sub-packet 1.1 overflows 64 bits"
+        );
+
+        // The sum of a literal of 17 groups of 0xF, one too many for 64 bits, and 1
+        let wide = Packet::from_hex("220084FFFFFFFFFFFFFFFFFFFF7881").unwrap();
+        let error = wide.checked_value().unwrap_err();
+        assert_eq!(error, EvalError::LiteralTooLarge { path: vec![0] });
+        assert_eq!(
+            error.to_string(),
+            "sub-packet 0 is a literal which does not fit in 64 bits"
+        );
+    }
+
+    #[test]
+    fn wide_literal() {
+        let sixteen = Literal::from(u64::MAX);
+        assert_eq!(sixteen.groups(), &[0xf; 16]);
+        assert_eq!(sixteen.to_u64(), Some(u64::MAX));
+        let seventeen = Literal::from_groups([0, 0, 1].into_iter().chain([0; 16]));
+        assert_eq!(seventeen.groups().len(), 17);
+        assert_eq!(seventeen.to_u64(), None);
+        assert_eq!(seventeen.to_string(), "18446744073709551616");
+        assert_eq!(Literal::from_groups([0, 0]), Literal::from(0));
+
+        let packet = Packet {
+            version: 5,
+            body: Body::Literal(seventeen),
+        };
+        let hex = packet.to_hex().unwrap();
+        assert_eq!(Packet::from_hex(&hex).unwrap(), packet);
+        assert_eq!(packet.to_string(), "18446744073709551616");
+    }
+
+    #[test]
+    #[cfg(feature = "bigint")]
+    fn big_value() {
+        let value = |source| compile(source).unwrap().big_value();
+        assert_eq!(value("max(3, 7*2) > 9"), BigUint::from(1_u8));
+        assert_eq!(
+            value("max(1, 2 * (18446744073709551615 + 1))"),
+            BigUint::from(2_u8).pow(65)
+        );
+        assert_eq!(
+            value("4294967296 * 4294967296 * 4294967296 > 18446744073709551615"),
+            BigUint::from(1_u8)
+        );
+        let wide = Packet::from_hex("220084FFFFFFFFFFFFFFFFFFFF7881").unwrap();
+        assert_eq!(wide.big_value(), BigUint::from(2_u8).pow(68));
+    }
+}
@@ -0,0 +1,386 @@
+//! Compile infix expressions, such as `max(3, 7 * 2) > 9`, to BITS packets.
+//!
+//! Expressions are made of numbers, `+`, `*`, the comparisons `>`, `<` and
+//! `==` (or `=`), parentheses, and the functions `min(..)` and `max(..)`.
+//! `*` binds tighter than `+`, which binds tighter than the comparisons,
+//! which cannot be chained.
+//!
+//! The packets are kept as small as they can be while still mirroring the
+//! expression: chains of the same operator, such as `1 + 2 + 3` or
+//! `max(max(1, 2), 3)`, become a single operator, operators of one operand
+//! disappear, every version is 0, and sub-packets are counted rather than
+//! measured, which takes 4 fewer bits, unless there are too many to count.
+use crate::bits::{Body, LengthType, Operator, Packet};
+use anyhow::{Context, Result};
+use utils::ParseError;
+
+/// Compile `source` to a packet which evaluates to the same value.
+pub fn compile(source: &str) -> Result<Packet, ParseError> {
+    let mut parser = Parser {
+        source,
+        rest: source,
+    };
+    let packet = parser.comparison()?;
+    match parser.peek() {
+        Some(token) => Err(ParseError::at(source, token, "the end of the expression")),
+        None => Ok(packet),
+    }
+}
+
+/// Compile `source` to a hexadecimal transmission.
+pub fn compile_to_hex(source: &str) -> Result<String> {
+    let packet = compile(source)?;
+    packet
+        .to_hex()
+        .with_context(|| format!("Failed to encode {}", packet))
+}
+
+/// Fewer than this many sub-packets can be counted in 11 bits.
+const MAX_COUNT: usize = 1 << 11;
+
+/// Apply `operator` to `operands`, merging in any operands which apply the
+/// same, associative, operator.
+fn apply(operator: Operator, operands: Vec<Packet>) -> Packet {
+    let associative = !matches!(
+        operator,
+        Operator::GreaterThan | Operator::LessThan | Operator::EqualTo
+    );
+    let mut packets = Vec::new();
+    for operand in operands {
+        match operand.body {
+            Body::Operator {
+                operator: inner,
+                packets: inner_packets,
+                ..
+            } if associative && inner == operator => packets.extend(inner_packets),
+            _ => packets.push(operand),
+        }
+    }
+    if associative && packets.len() == 1 {
+        return packets.remove(0);
+    }
+    let length = if packets.len() < MAX_COUNT {
+        LengthType::Packets
+    } else {
+        LengthType::Bits
+    };
+    Packet::operator(0, operator, length, packets)
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    /// The unparsed tail of `source`.
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    /// The next token: a number, a name, `==`, or any other single character.
+    fn peek(&mut self) -> Option<&'a str> {
+        self.rest = self.rest.trim_start();
+        let mut chars = self.rest.char_indices();
+        let (_, first) = chars.next()?;
+        let len = if first.is_ascii_digit() || first.is_alphabetic() {
+            chars
+                .find(|(_, c)| !c.is_alphanumeric())
+                .map_or(self.rest.len(), |(i, _)| i)
+        } else if self.rest.starts_with("==") {
+            2
+        } else {
+            first.len_utf8()
+        };
+        Some(&self.rest[..len])
+    }
+
+    fn advance(&mut self, token: &str) {
+        self.rest = &self.rest[token.len()..];
+    }
+
+    /// Consume `expected`, which must be the next token.
+    fn expect(&mut self, expected: &str) -> Result<(), ParseError> {
+        match self.peek() {
+            Some(token) if token == expected => {
+                self.advance(token);
+                Ok(())
+            }
+            Some(token) => Err(ParseError::at(
+                self.source,
+                token,
+                format!("{:?}", expected),
+            )),
+            None => Err(ParseError::end_of_line(
+                self.source,
+                format!("{:?}", expected),
+            )),
+        }
+    }
+
+    fn comparison(&mut self) -> Result<Packet, ParseError> {
+        let left = self.sum()?;
+        let operator = match self.peek() {
+            Some(">") => Operator::GreaterThan,
+            Some("<") => Operator::LessThan,
+            Some("==") | Some("=") => Operator::EqualTo,
+            _ => return Ok(left),
+        };
+        let token = self.peek().unwrap_or_default();
+        self.advance(token);
+        let right = self.sum()?;
+        Ok(apply(operator, vec![left, right]))
+    }
+
+    fn sum(&mut self) -> Result<Packet, ParseError> {
+        let mut operands = vec![self.product()?];
+        while self.peek() == Some("+") {
+            self.advance("+");
+            operands.push(self.product()?);
+        }
+        Ok(apply(Operator::Sum, operands))
+    }
+
+    fn product(&mut self) -> Result<Packet, ParseError> {
+        let mut operands = vec![self.operand()?];
+        while self.peek() == Some("*") {
+            self.advance("*");
+            operands.push(self.operand()?);
+        }
+        Ok(apply(Operator::Product, operands))
+    }
+
+    fn operand(&mut self) -> Result<Packet, ParseError> {
+        const EXPECTED: &str = "a number, \"(\", \"min\" or \"max\"";
+        let token = match self.peek() {
+            Some(token) => token,
+            None => return Err(ParseError::end_of_line(self.source, EXPECTED)),
+        };
+        if token == "(" {
+            self.advance(token);
+            let packet = self.comparison()?;
+            self.expect(")")?;
+            return Ok(packet);
+        }
+        let operator = match token {
+            "min" => Operator::Minimum,
+            "max" => Operator::Maximum,
+            _ if token.starts_with(|c: char| c.is_ascii_digit()) => {
+                let value = token.parse().map_err(|_| {
+                    ParseError::at(self.source, token, "a number that fits in 64 bits")
+                })?;
+                self.advance(token);
+                return Ok(Packet::literal(0, value));
+            }
+            _ => return Err(ParseError::at(self.source, token, EXPECTED)),
+        };
+        self.advance(token);
+        self.expect("(")?;
+        let mut operands = vec![self.comparison()?];
+        while self.peek() == Some(",") {
+            self.advance(",");
+            operands.push(self.comparison()?);
+        }
+        self.expect(")")?;
+        Ok(apply(operator, operands))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn example() {
+        let packet = compile("max(3, 7*2) > 9").unwrap();
+        assert_eq!(packet.to_string(), "(> (max 3 (* 7 2)) 9)");
+        assert_eq!(packet.checked_value(), Ok(1));
+        let hex = compile_to_hex("max(3, 7*2) > 9").unwrap();
+        assert_eq!(hex, "1600838021060C010871042240");
+        assert_eq!(Packet::from_hex(&hex).unwrap(), packet);
+    }
+
+    #[test]
+    fn flattens_chains() {
+        assert_eq!(compile("1 + (2 + 3)").unwrap().to_string(), "(+ 1 2 3)");
+        assert_eq!(
+            compile("max(max(1, 2), 3 * 4 * (5 * 6))")
+                .unwrap()
+                .to_string(),
+            "(max 1 2 (* 3 4 5 6))"
+        );
+        assert_eq!(compile("min((7))").unwrap(), Packet::literal(0, 7));
+    }
+
+    #[test]
+    fn length_types() {
+        let packet = compile("1 + 2").unwrap();
+        assert!(matches!(
+            packet.body,
+            Body::Operator {
+                length: LengthType::Packets,
+                ..
+            }
+        ));
+        let source = vec!["1"; MAX_COUNT].join("+");
+        let packet = compile(&source).unwrap();
+        assert!(matches!(
+            packet.body,
+            Body::Operator {
+                length: LengthType::Bits,
+                ..
+            }
+        ));
+        assert_eq!(Packet::from_hex(&packet.to_hex().unwrap()).unwrap(), packet);
+    }
+
+    #[test]
+    fn errors() {
+        let error = |source| compile(source).unwrap_err().to_string();
+        assert_eq!(
+            error("1 + * 2"),
+            "column 5: expected a number, \"(\", \"min\" or \"max\", found \"*\""
+        );
+        assert_eq!(
+            error("1 < 2 < 3"),
+            "column 7: expected the end of the expression, found \"<\""
+        );
+        assert_eq!(
+            error("max(1, 2"),
+            "column 9: expected \")\", found end of line"
+        );
+        assert_eq!(
+            error("99999999999999999999"),
+            "column 1: expected a number that fits in 64 bits, found \"99999999999999999999\""
+        );
+        assert!(compile_to_hex("1 ? 2").is_err());
+    }
+
+    /// An expression, and the value it should have.
+    #[derive(Debug, Clone)]
+    enum Expr {
+        Number(u64),
+        Add(Box<Expr>, Box<Expr>),
+        Mul(Box<Expr>, Box<Expr>),
+        Min(Vec<Expr>),
+        Max(Vec<Expr>),
+        Gt(Box<Expr>, Box<Expr>),
+        Lt(Box<Expr>, Box<Expr>),
+        Eq(Box<Expr>, Box<Expr>),
+    }
+
+    impl Expr {
+        fn source(&self) -> String {
+            use Expr::*;
+            let list = |exprs: &[Expr]| {
+                exprs
+                    .iter()
+                    .map(|expr| expr.source())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            match self {
+                Number(value) => value.to_string(),
+                Add(a, b) => format!("({} + {})", a.source(), b.source()),
+                Mul(a, b) => format!("{} * {}", a.bracketed(), b.bracketed()),
+                Min(exprs) => format!("min({})", list(exprs)),
+                Max(exprs) => format!("max({})", list(exprs)),
+                Gt(a, b) => format!("({} > {})", a.source(), b.source()),
+                Lt(a, b) => format!("({} < {})", a.source(), b.source()),
+                Eq(a, b) => format!("({} == {})", a.source(), b.source()),
+            }
+        }
+
+        /// The source, in parentheses unless it is a single operand.
+        fn bracketed(&self) -> String {
+            match self {
+                Expr::Mul(..) => format!("({})", self.source()),
+                _ => self.source(),
+            }
+        }
+
+        fn value(&self) -> Option<u64> {
+            use Expr::*;
+            let values =
+                |exprs: &[Expr]| exprs.iter().map(|e| e.value()).collect::<Option<Vec<_>>>();
+            Some(match self {
+                Number(value) => *value,
+                Add(a, b) => a.value()?.checked_add(b.value()?)?,
+                Mul(a, b) => a.value()?.checked_mul(b.value()?)?,
+                Min(exprs) => values(exprs)?.into_iter().min()?,
+                Max(exprs) => values(exprs)?.into_iter().max()?,
+                Gt(a, b) => (a.value()? > b.value()?) as u64,
+                Lt(a, b) => (a.value()? < b.value()?) as u64,
+                Eq(a, b) => (a.value()? == b.value()?) as u64,
+            })
+        }
+    }
+
+    fn expr() -> impl Strategy<Value = Expr> {
+        let leaf = prop_oneof![0..20_u64, any::<u64>()].prop_map(Expr::Number);
+        leaf.prop_recursive(4, 32, 4, |inner| {
+            let pair = (inner.clone(), inner.clone()).prop_map(|(a, b)| (Box::new(a), Box::new(b)));
+            prop_oneof![
+                pair.clone().prop_map(|(a, b)| Expr::Add(a, b)),
+                pair.clone().prop_map(|(a, b)| Expr::Mul(a, b)),
+                prop::collection::vec(inner.clone(), 1..4).prop_map(Expr::Min),
+                prop::collection::vec(inner, 1..4).prop_map(Expr::Max),
+                pair.clone().prop_map(|(a, b)| Expr::Gt(a, b)),
+                pair.clone().prop_map(|(a, b)| Expr::Lt(a, b)),
+                pair.prop_map(|(a, b)| Expr::Eq(a, b)),
+            ]
+        })
+    }
+
+    fn packet() -> impl Strategy<Value = Packet> {
+        let length = prop_oneof![Just(LengthType::Bits), Just(LengthType::Packets)];
+        let leaf =
+            (0..8_u8, any::<u64>()).prop_map(|(version, value)| Packet::literal(version, value));
+        leaf.prop_recursive(4, 64, 5, move |inner| {
+            let operator = prop_oneof![
+                Just(Operator::Sum),
+                Just(Operator::Product),
+                Just(Operator::Minimum),
+                Just(Operator::Maximum),
+            ];
+            let comparison = prop_oneof![
+                Just(Operator::GreaterThan),
+                Just(Operator::LessThan),
+                Just(Operator::EqualTo),
+            ];
+            prop_oneof![
+                (
+                    0..8_u8,
+                    operator,
+                    length.clone(),
+                    prop::collection::vec(inner.clone(), 1..5)
+                ),
+                (
+                    0..8_u8,
+                    comparison,
+                    length.clone(),
+                    prop::collection::vec(inner, 2..=2)
+                ),
+            ]
+            .prop_map(|(version, operator, length, packets)| {
+                Packet::operator(version, operator, length, packets)
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn compiled_value(expr in expr()) {
+            let source = expr.source();
+            let hex = compile_to_hex(&source).unwrap();
+            let packet = Packet::from_hex(&hex).unwrap();
+            prop_assert_eq!(&packet, &compile(&source).unwrap());
+            if let Some(value) = expr.value() {
+                prop_assert_eq!(packet.checked_value(), Ok(value));
+            }
+        }
+
+        #[test]
+        fn round_trip(packet in packet()) {
+            let hex = packet.to_hex().unwrap();
+            prop_assert_eq!(Packet::from_hex(&hex).unwrap(), packet);
+        }
+    }
+}
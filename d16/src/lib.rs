@@ -0,0 +1,212 @@
+use anyhow::{Context, Result};
+use bits::Packet;
+use log::debug;
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
+use std::io::BufRead;
+use utils::{ParseError, Solver};
+
+pub mod bits;
+pub mod compile;
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    /// Values are limited to 64 bits, unless the `bigint` feature lifts the
+    /// limit.
+    #[cfg(not(feature = "bigint"))]
+    type Answer = u64;
+    #[cfg(feature = "bigint")]
+    type Answer = BigUint;
+
+    fn parse<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+
+        let hex = check_hex(&line).map_err(|e| e.with_line(1))?;
+        let packet = Packet::from_hex(hex).context("Failed to decode the transmission")?;
+        Ok(Solution::new(packet))
+    }
+
+    // Only a conversion with the `bigint` feature.
+    #[allow(clippy::useless_conversion)]
+    fn part1(&mut self) -> Result<Self::Answer> {
+        Ok(self.packet.version_total().into())
+    }
+
+    #[cfg(not(feature = "bigint"))]
+    fn part2(&mut self) -> Result<Self::Answer> {
+        self.packet
+            .checked_value()
+            .context("Failed to evaluate the transmission")
+    }
+
+    #[cfg(feature = "bigint")]
+    fn part2(&mut self) -> Result<Self::Answer> {
+        Ok(self.packet.big_value())
+    }
+}
+
+#[derive(Debug)]
+pub struct Solution {
+    packet: Packet,
+}
+
+impl Solution {
+    fn new(packet: Packet) -> Self {
+        debug!("{}", packet);
+        Self { packet }
+    }
+}
+
+/// Check that `line` is a hexadecimal transmission, in either case.
+fn check_hex(line: &str) -> Result<&str, ParseError> {
+    let hex = line.trim();
+    match hex.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+        Some((i, c)) => Err(ParseError::at(
+            line,
+            &hex[i..i + c.len_utf8()],
+            "a hexadecimal digit",
+        )),
+        None => Ok(hex),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use utils::Solver;
+
+    #[ctor::ctor]
+    fn init() {
+        env_logger::init();
+    }
+
+    #[allow(clippy::useless_conversion)]
+    fn answer(value: u64) -> <Solution as Solver>::Answer {
+        value.into()
+    }
+
+    #[test]
+    fn version_total_1() {
+        let mut solution = Solution::parse_str("D2FE28").unwrap();
+        assert_eq!(solution.part1().unwrap(), answer(6));
+    }
+
+    #[test]
+    fn version_total_2() {
+        let mut solution = Solution::parse_str("8A004A801A8002F478").unwrap();
+        assert_eq!(solution.part1().unwrap(), answer(16));
+    }
+
+    #[test]
+    fn version_total_3() {
+        let mut solution = Solution::parse_str("620080001611562C8802118E34").unwrap();
+        assert_eq!(solution.part1().unwrap(), answer(12));
+    }
+
+    #[test]
+    fn version_total_4() {
+        let mut solution = Solution::parse_str("C0015000016115A2E0802F182340").unwrap();
+        assert_eq!(solution.part1().unwrap(), answer(23));
+    }
+
+    #[test]
+    fn version_total_5() {
+        let mut solution = Solution::parse_str("A0016C880162017C3686B18A3D4780").unwrap();
+        assert_eq!(solution.part1().unwrap(), answer(31));
+    }
+
+    #[test]
+    fn version_total_6() {
+        let mut solution = Solution::parse_str("38006F45291200").unwrap();
+        assert_eq!(solution.part1().unwrap(), answer(9));
+    }
+
+    #[test]
+    fn lowercase() {
+        let mut solution = Solution::parse_str("8a004a801a8002f478").unwrap();
+        assert_eq!(solution.part1().unwrap(), answer(16));
+        let error = Solution::parse_str("8A004G").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 6: expected a hexadecimal digit, found \"G\""
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        let error = Solution::parse_str("38006F4529").unwrap_err();
+        assert_eq!(
+            error.root_cause().to_string(),
+            "bit 40: the transmission ends while reading a literal group, which needs 4 bits but only 0 remain"
+        );
+    }
+
+    #[test]
+    fn value_1() {
+        let mut solution = Solution::parse_str("C200B40A82").unwrap();
+        assert_eq!(solution.part2().unwrap(), answer(3));
+    }
+
+    #[test]
+    fn value_2() {
+        let mut solution = Solution::parse_str("04005AC33890").unwrap();
+        assert_eq!(solution.part2().unwrap(), answer(54));
+    }
+
+    #[test]
+    fn value_3() {
+        let mut solution = Solution::parse_str("880086C3E88112").unwrap();
+        assert_eq!(solution.part2().unwrap(), answer(7));
+    }
+
+    #[test]
+    fn value_4() {
+        let mut solution = Solution::parse_str("CE00C43D881120").unwrap();
+        assert_eq!(solution.part2().unwrap(), answer(9));
+    }
+
+    #[test]
+    fn value_5() {
+        let mut solution = Solution::parse_str("D8005AC2A8F0").unwrap();
+        assert_eq!(solution.part2().unwrap(), answer(1));
+    }
+
+    #[test]
+    fn value_6() {
+        let mut solution = Solution::parse_str("F600BC2D8F").unwrap();
+        assert_eq!(solution.part2().unwrap(), answer(0));
+    }
+
+    #[test]
+    fn value_7() {
+        let mut solution = Solution::parse_str("9C005AC2F8F0").unwrap();
+        assert_eq!(solution.part2().unwrap(), answer(0));
+    }
+
+    #[test]
+    fn value_8() {
+        let mut solution = Solution::parse_str("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(solution.part2().unwrap(), answer(1));
+    }
+
+    #[test]
+    fn wide_literal() {
+        // (2^68 - 1) + 1, where the first literal is a group wider than 64 bits
+        let mut solution = Solution::parse_str("220084FFFFFFFFFFFFFFFFFFFF7881").unwrap();
+        #[cfg(not(feature = "bigint"))]
+        assert_eq!(
+            format!("{:#}", solution.part2().unwrap_err()),
+            "Failed to evaluate the transmission: \
+             sub-packet 0 is a literal which does not fit in 64 bits"
+        );
+        #[cfg(feature = "bigint")]
+        assert_eq!(
+            solution.part2().unwrap().to_string(),
+            "295147905179352825856"
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use anyhow::Result;
+use d16::Solution;
+use log::info;
+use utils::Solver;
+
+fn main() -> Result<()> {
+    env_logger::init();
+
+    let (part, mut solution) = Solution::from_args(16)?;
+    info!("solution: {:?}", solution);
+    info!("answer is {}", solution.solve(part)?);
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use anyhow::{Context, Result};
+use log::debug;
+use regex::Regex;
+use std::cmp::{max, min};
+use std::io::BufRead;
+use std::str::FromStr;
+use utils::{parse_at, ParseError, Solver};
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
+
+    fn parse<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+
+        Solution::from_str(&line)
+            .with_context(|| format!("Failed to parse solution input from {}", line))
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        self.hits()
+            .into_iter()
+            .max()
+            .context("No launch reaches the target area")
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        Ok(self.hits().len() as i64)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Solution {
+    sx: i64,
+    ex: i64,
+    sy: i64,
+    ey: i64,
+}
+
+impl Solution {
+    /// The highest point reached by each launch that ends up in the target
+    /// area. Any faster launch overshoots it on the first step, or the first
+    /// step after falling back to the start.
+    fn hits(&self) -> Vec<i64> {
+        let reach = max(self.sy.abs(), self.ey.abs());
+        let mut heights = Vec::new();
+        for vy in -reach..=reach {
+            for vx in min(self.sx, self.ex).min(0)..=max(self.sx, self.ex).max(0) {
+                if let Some(height) = self.simulate(vx, vy) {
+                    debug!("hit ({} {}) => {}", vx, vy, height);
+                    heights.push(height);
+                }
+            }
+        }
+        heights
+    }
+
+    fn in_target_area(&self, x: i64, y: i64) -> bool {
+        x >= self.sx && x <= self.ex && y >= self.sy && y <= self.ey
+    }
+
+    /// The highest point reached by a launch at `(vx, vy)`, if it ends up
+    /// in the target area.
+    fn simulate(&self, mut vx: i64, mut vy: i64) -> Option<i64> {
+        let mut x = 0;
+        let mut y = 0;
+        let mut height = 0;
+
+        loop {
+            x += vx;
+            y += vy;
+            height = max(height, y);
+            vx += match vx {
+                _p if vx > 0 => -1,
+                _n if vx < 0 => 1,
+                _z => 0,
+            };
+            vy -= 1;
+
+            if self.in_target_area(x, y) {
+                break Some(height);
+            }
+            if y < min(self.sy, self.ey) {
+                break None;
+            }
+        }
+    }
+}
+
+impl FromStr for Solution {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(
+            r"^target area: x=(?P<sx>-?\d+)\.\.(?P<ex>-?\d+), y=(?P<sy>-?\d+)\.\.(?P<ey>-?\d+)$",
+        )
+        .unwrap();
+        debug!("input: {}", s);
+        let line = s.trim();
+        let caps = re.captures(line).ok_or_else(|| {
+            ParseError::at(s, line, "target area: x=<min>..<max>, y=<min>..<max>")
+        })?;
+        for n in re.capture_names().flatten() {
+            debug!("{} => {:?}", n, caps.name(n).map(|v| v.as_str()));
+        }
+        let sx = parse_at(s, &caps["sx"], "a number")?;
+        let sy = parse_at(s, &caps["sy"], "a number")?;
+        let ex = parse_at(s, &caps["ex"], "a number")?;
+        let ey = parse_at(s, &caps["ey"], "a number")?;
+
+        Ok(Self { sx, ex, sy, ey })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use std::str::FromStr;
+    use utils::Solver;
+
+    #[ctor::ctor]
+    fn init() {
+        env_logger::init();
+    }
+
+    #[test]
+    fn parse_input() {
+        let solution = Solution::from_str("target area: x=20..30, y=-10..-5").unwrap();
+        assert_eq!(solution.sx, 20);
+        assert_eq!(solution.ex, 30);
+        assert_eq!(solution.sy, -10);
+        assert_eq!(solution.ey, -5);
+    }
+
+    #[test]
+    fn in_target_area() {
+        let solution = Solution::from_str("target area: x=20..30, y=-10..-5").unwrap();
+        assert!(solution.in_target_area(20, -10));
+        assert!(!solution.in_target_area(21, -11));
+        assert!(solution.in_target_area(30, -5));
+        assert!(!solution.in_target_area(20, -4));
+    }
+
+    #[test]
+    fn hit() {
+        let solution = Solution::from_str("target area: x=20..30, y=-10..-5").unwrap();
+        assert!(solution.simulate(7, 2).is_some());
+        assert!(solution.simulate(6, 3).is_some());
+        assert!(solution.simulate(9, 0).is_some());
+        assert!(solution.simulate(17, -4).is_none());
+    }
+
+    #[test]
+    fn height() {
+        let solution = Solution::from_str("target area: x=20..30, y=-10..-5").unwrap();
+        assert_eq!(solution.simulate(6, 9), Some(45));
+    }
+
+    #[test]
+    fn max_height() {
+        let mut solution = Solution::from_str("target area: x=20..30, y=-10..-5").unwrap();
+        assert_eq!(solution.part1().unwrap(), 45);
+    }
+
+    #[test]
+    fn hit_count() {
+        let mut solution = Solution::from_str("target area: x=20..30, y=-10..-5").unwrap();
+        assert_eq!(solution.part2().unwrap(), 112);
+    }
+}
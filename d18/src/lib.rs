@@ -0,0 +1,179 @@
+use anyhow::{Context, Result};
+use log::debug;
+use snailfish::SnailfishNumber;
+use std::io::BufRead;
+use std::str::FromStr;
+use utils::Solver;
+
+pub mod snailfish;
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = u64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut solution = Solution::new();
+        for (number, line) in reader.lines().enumerate() {
+            let number = SnailfishNumber::from_str(&line?).map_err(|e| e.with_line(number + 1))?;
+            solution.add(number);
+        }
+        Ok(solution)
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        let sum = self.sum().context("There are no numbers to add")?;
+        debug!("sum: {}", sum);
+        Ok(sum.magnitude())
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        snailfish::largest_sum_magnitude(&self.numbers)
+            .context("There are fewer than two numbers to add")
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Solution {
+    numbers: Vec<SnailfishNumber>,
+}
+
+impl Solution {
+    fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    fn add(&mut self, number: SnailfishNumber) {
+        self.numbers.push(number);
+    }
+
+    /// All the numbers added up, in order.
+    fn sum(&self) -> Option<SnailfishNumber> {
+        self.numbers.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::str::FromStr;
+
+    #[ctor::ctor]
+    fn init() {
+        env_logger::init();
+    }
+
+    #[test]
+    fn addition() {
+        test_list_addition(
+            vec![r"[[[[4,3],4],4],[7,[[8,4],9]]]", r"[1,1]"],
+            r"[[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
+        );
+        test_list_addition(
+            vec![r"[1,1]", r"[2,2]", r"[3,3]", r"[4,4]"],
+            r"[[[[1,1],[2,2]],[3,3]],[4,4]]",
+        );
+        test_list_addition(
+            vec![r"[1,1]", r"[2,2]", r"[3,3]", r"[4,4]", r"[5,5]"],
+            r"[[[[3,0],[5,3]],[4,4]],[5,5]]",
+        );
+        test_list_addition(
+            vec![r"[1,1]", r"[2,2]", r"[3,3]", r"[4,4]", r"[5,5]", r"[6,6]"],
+            r"[[[[5,0],[7,4]],[5,5]],[6,6]]",
+        );
+    }
+
+    #[test]
+    fn load_error() {
+        let error = Solution::parse_str("[1,2]\n[[1,2],3,4]\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 9: expected a closing bracket, found \",\""
+        );
+    }
+
+    #[test]
+    fn list_magnitude() {
+        test_list_magnitude(
+            vec![
+                r"[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
+                r"[[[5,[2,8]],4],[5,[[9,9],0]]]",
+                r"[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
+                r"[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
+                r"[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
+                r"[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
+                r"[[[[5,4],[7,7]],8],[[8,3],8]]",
+                r"[[9,3],[[9,9],[6,[4,9]]]]",
+                r"[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
+                r"[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+            ],
+            r"[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]",
+            4140,
+        );
+
+        test_list_addition(
+            vec![
+                r"[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]",
+                r"[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]",
+            ],
+            r"[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]",
+        );
+
+        test_list_magnitude(
+            vec![
+                r"[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]",
+                r"[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]",
+                r"[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]",
+                r"[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]",
+                r"[7,[5,[[3,8],[1,4]]]]",
+                r"[[2,[2,2]],[8,[8,1]]]",
+                r"[2,9]",
+                r"[1,[[[9,3],9],[[9,0],[0,7]]]]",
+                r"[[[5,[7,4]],7],1]",
+                r"[[[[4,2],2],6],[8,7]]",
+            ],
+            r"[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+            3488,
+        );
+    }
+
+    #[test]
+    fn largest_pair_magnitude() {
+        let mut solution = Solution::parse_str(
+            &[
+                r"[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
+                r"[[[5,[2,8]],4],[5,[[9,9],0]]]",
+                r"[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
+                r"[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
+                r"[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
+                r"[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
+                r"[[[[5,4],[7,7]],8],[[8,3],8]]",
+                r"[[9,3],[[9,9],[6,[4,9]]]]",
+                r"[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
+                r"[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+            ]
+            .join("\n"),
+        )
+        .unwrap();
+        assert_eq!(solution.part1().unwrap(), 4140);
+        assert_eq!(solution.part2().unwrap(), 3993);
+    }
+
+    fn test_list_addition(input: Vec<&str>, expected: &str) {
+        let expected = SnailfishNumber::from_str(expected).unwrap();
+        let solution = Solution::parse_str(&input.join("\n")).unwrap();
+        assert_eq!(solution.sum().unwrap(), expected);
+    }
+
+    fn test_list_magnitude(input: Vec<&str>, expected_sum: &str, expected: u64) {
+        let expected_sum = SnailfishNumber::from_str(expected_sum).unwrap();
+        let solution = Solution::parse_str(&input.join("\n")).unwrap();
+        let result = solution.sum().unwrap();
+        assert_eq!(result, expected_sum);
+        assert_eq!(result.magnitude(), expected);
+    }
+}
@@ -1,14 +1,14 @@
 use anyhow::Result;
-use d17p2::load;
+use d18::Solution;
 use log::{debug, info};
+use utils::Solver;
 
 fn main() -> Result<()> {
     env_logger::init();
 
-    let mut solution = load("input.d17p1.full")?;
+    let (part, mut solution) = Solution::from_args(18)?;
     debug!("solution: {:?}", solution);
-    solution.analyse();
-    info!("answer is {}", solution.answer()?);
+    info!("answer is {}", solution.solve(part)?);
 
     Ok(())
 }
@@ -0,0 +1,469 @@
+//! Snailfish numbers, stored flat.
+//!
+//! A snailfish number is a pair whose halves are regular numbers or pairs.
+//! Arithmetic only ever looks at the regular numbers in order, and at how
+//! deeply each is nested, so a number is stored as that list of `(value,
+//! depth)` elements rather than as a tree: `[[1,2],3]` is `(1, 2), (2, 2),
+//! (3, 1)`.
+use log::trace;
+use rayon::prelude::*;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+use std::str::FromStr;
+use utils::{parse_at, ParseError};
+
+/// Pairs nested inside this many pairs explode.
+const EXPLODE_DEPTH: u8 = 4;
+
+/// Regular numbers this large split.
+const SPLIT_VALUE: u32 = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SnailfishNumber {
+    /// Each regular number, left to right, with the number of pairs around it.
+    elements: Vec<(u32, u8)>,
+}
+
+/// One action taken while reducing a snailfish number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// The leftmost pair nested inside four others, of these two regular
+    /// numbers, exploded.
+    Explode(u32, u32),
+    /// The leftmost regular number of 10 or more split in two.
+    Split(u32),
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Explode(left, right) => write!(f, "explode [{},{}]", left, right),
+            Step::Split(value) => write!(f, "split {}", value),
+        }
+    }
+}
+
+impl SnailfishNumber {
+    /// The magnitude: three times that of the left half of a pair, plus twice
+    /// that of the right.
+    pub fn magnitude(&self) -> u64 {
+        // Combine the top two entries whenever they are the halves of a pair.
+        let mut stack: Vec<(u64, u8)> = Vec::with_capacity(EXPLODE_DEPTH as usize + 2);
+        for &(value, depth) in &self.elements {
+            stack.push((value as u64, depth));
+            while let [.., (left, left_depth), (right, right_depth)] = stack[..] {
+                if left_depth != right_depth {
+                    break;
+                }
+                stack.truncate(stack.len() - 2);
+                stack.push((3 * left + 2 * right, left_depth - 1));
+            }
+        }
+        stack.first().map_or(0, |(magnitude, _)| *magnitude)
+    }
+
+    /// Take a single step towards reducing the number, returning what was
+    /// done, or `None` if it is already reduced.
+    pub fn reduce_step(&mut self) -> Option<Step> {
+        self.explode().or_else(|| self.split())
+    }
+
+    /// Explode and split until neither applies.
+    pub fn reduce(&mut self) {
+        while let Some(step) = self.reduce_step() {
+            trace!("{} -> {}", step, self);
+        }
+    }
+
+    /// Reduce the number a step at a time.
+    pub fn reduction(self) -> Reduction {
+        Reduction { number: self }
+    }
+
+    /// Explode the leftmost pair of regular numbers nested inside four pairs.
+    fn explode(&mut self) -> Option<Step> {
+        let elements = &mut self.elements;
+        let i = elements
+            .windows(2)
+            .position(|pair| pair[0].1 > EXPLODE_DEPTH && pair[0].1 == pair[1].1)?;
+        let ((left, depth), (right, _)) = (elements[i], elements[i + 1]);
+        if i > 0 {
+            elements[i - 1].0 += left;
+        }
+        if let Some((value, _)) = elements.get_mut(i + 2) {
+            *value += right;
+        }
+        elements[i] = (0, depth - 1);
+        elements.remove(i + 1);
+        Some(Step::Explode(left, right))
+    }
+
+    /// Split the leftmost regular number of 10 or more into a pair.
+    fn split(&mut self) -> Option<Step> {
+        let elements = &mut self.elements;
+        let i = elements
+            .iter()
+            .position(|(value, _)| *value >= SPLIT_VALUE)?;
+        let (value, depth) = elements[i];
+        elements[i] = (value / 2, depth + 1);
+        elements.insert(i + 1, (value - value / 2, depth + 1));
+        Some(Step::Split(value))
+    }
+}
+
+/// A snailfish number being reduced, one [`Step`] per item.
+#[derive(Debug, Clone)]
+pub struct Reduction {
+    number: SnailfishNumber,
+}
+
+impl Reduction {
+    /// The number as it stands after the steps so far.
+    pub fn number(&self) -> &SnailfishNumber {
+        &self.number
+    }
+
+    pub fn into_number(self) -> SnailfishNumber {
+        self.number
+    }
+}
+
+impl Iterator for Reduction {
+    type Item = Step;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.number.reduce_step()
+    }
+}
+
+impl AddAssign<&SnailfishNumber> for SnailfishNumber {
+    fn add_assign(&mut self, other: &SnailfishNumber) {
+        self.elements.extend_from_slice(&other.elements);
+        for (_, depth) in &mut self.elements {
+            *depth += 1;
+        }
+        self.reduce();
+    }
+}
+
+impl AddAssign for SnailfishNumber {
+    fn add_assign(&mut self, other: SnailfishNumber) {
+        *self += &other;
+    }
+}
+
+impl Add<&SnailfishNumber> for SnailfishNumber {
+    type Output = SnailfishNumber;
+
+    fn add(mut self, other: &SnailfishNumber) -> Self::Output {
+        self += other;
+        self
+    }
+}
+
+impl Add for SnailfishNumber {
+    type Output = SnailfishNumber;
+
+    fn add(self, other: SnailfishNumber) -> Self::Output {
+        self + &other
+    }
+}
+
+/// Snailfish numbers have no zero, so there is no sum of none of them.
+impl Sum<SnailfishNumber> for Option<SnailfishNumber> {
+    fn sum<I: Iterator<Item = SnailfishNumber>>(iter: I) -> Self {
+        iter.reduce(|sum, number| sum + number)
+    }
+}
+
+impl<'a> Sum<&'a SnailfishNumber> for Option<SnailfishNumber> {
+    fn sum<I: Iterator<Item = &'a SnailfishNumber>>(mut iter: I) -> Self {
+        let first = iter.next()?.clone();
+        Some(iter.fold(first, |sum, number| sum + number))
+    }
+}
+
+/// The largest magnitude of the sum of two different numbers from `numbers`,
+/// or `None` if there are fewer than two.
+pub fn largest_sum_magnitude(numbers: &[SnailfishNumber]) -> Option<u64> {
+    (0..numbers.len())
+        .into_par_iter()
+        .flat_map_iter(|i| (0..numbers.len()).map(move |j| (i, j)))
+        .filter(|(i, j)| i != j)
+        .map_init(
+            || SnailfishNumber {
+                elements: Vec::new(),
+            },
+            |sum, (i, j)| {
+                // Reuse one buffer per thread rather than cloning each pair.
+                sum.elements.clone_from(&numbers[i].elements);
+                *sum += &numbers[j];
+                sum.magnitude()
+            },
+        )
+        .max()
+}
+
+impl fmt::Display for SnailfishNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // For each pair we are inside, whether we are in its right half yet.
+        let mut path: Vec<bool> = Vec::with_capacity(EXPLODE_DEPTH as usize + 1);
+        for &(value, depth) in &self.elements {
+            while path.len() < depth as usize {
+                write!(f, "[")?;
+                path.push(false);
+            }
+            write!(f, "{}", value)?;
+            while let Some(right) = path.last_mut() {
+                if !*right {
+                    *right = true;
+                    write!(f, ",")?;
+                    break;
+                }
+                write!(f, "]")?;
+                path.pop();
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SnailfishNumber {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = s.trim();
+        let mut elements = Vec::new();
+        let rest = parse_element(line, line, 0, &mut elements)?;
+        if !rest.is_empty() {
+            return Err(ParseError::at_start(line, rest, "the end of the number"));
+        }
+        Ok(Self { elements })
+    }
+}
+
+/// Parse a regular number or a pair, nested `depth` deep, from the start of
+/// `rest`, the unparsed tail of `line`. Returns what is left.
+fn parse_element<'a>(
+    line: &str,
+    rest: &'a str,
+    depth: u8,
+    elements: &mut Vec<(u32, u8)>,
+) -> Result<&'a str, ParseError> {
+    if let Some(inner) = rest.strip_prefix('[') {
+        // Leave room to nest the number once more when adding it.
+        if depth == u8::MAX - 1 {
+            return Err(ParseError::at_start(
+                line,
+                rest,
+                "a less deeply nested pair",
+            ));
+        }
+        let rest = parse_element(line, inner, depth + 1, elements)?;
+        let rest = expect(line, rest, ',', "a comma")?;
+        let rest = parse_element(line, rest, depth + 1, elements)?;
+        expect(line, rest, ']', "a closing bracket")
+    } else {
+        let length = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if length == 0 {
+            return Err(ParseError::at_start(line, rest, "a number or a pair"));
+        }
+        elements.push((parse_at(line, &rest[..length], "a number")?, depth));
+        Ok(&rest[length..])
+    }
+}
+
+/// Skip `c` at the start of `rest`, the unparsed tail of `line`.
+fn expect<'a>(line: &str, rest: &'a str, c: char, expected: &str) -> Result<&'a str, ParseError> {
+    rest.strip_prefix(c)
+        .ok_or_else(|| ParseError::at_start(line, rest, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(s: &str) -> SnailfishNumber {
+        SnailfishNumber::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        for s in [
+            "7",
+            "[1,2]",
+            "[1,[2,3]]",
+            "[[1,2],3]",
+            "[7,[6,[5,[4,[3,2]]]]]",
+            "[[2,[3,[4,5]]],[[[0,1],2],3]]",
+            "[[[[[9,8],1],2],3],4]",
+        ] {
+            assert_eq!(number(s).to_string(), s);
+        }
+        assert_eq!(number("[[1,2],3]").elements, vec![(1, 2), (2, 2), (3, 1)]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        for (s, error) in [
+            (
+                "",
+                "column 1: expected a number or a pair, found end of line",
+            ),
+            (
+                "[1,2",
+                "column 5: expected a closing bracket, found end of line",
+            ),
+            ("[1;2]", "column 3: expected a comma, found \";\""),
+            (
+                "[1,x]",
+                "column 4: expected a number or a pair, found \"x\"",
+            ),
+            (
+                "[1,2]]",
+                "column 6: expected the end of the number, found \"]\"",
+            ),
+            (
+                "[1,99999999999]",
+                "column 4: expected a number, found \"99999999999\"",
+            ),
+        ] {
+            assert_eq!(
+                SnailfishNumber::from_str(s).unwrap_err().to_string(),
+                error,
+                "{}",
+                s
+            );
+        }
+    }
+
+    fn test_explode(src: &str, expected: &str) {
+        let mut src = number(src);
+        assert!(src.explode().is_some());
+        assert_eq!(src, number(expected));
+    }
+
+    #[test]
+    fn explode() {
+        test_explode("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]");
+        test_explode("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]");
+        test_explode(
+            "[[2,[3,[4,[3,2]]]],[[[0,1],2],3]]",
+            "[[2,[3,[7,0]]],[[[2,1],2],3]]",
+        );
+        test_explode("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]");
+        test_explode(
+            "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+            "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+        );
+        test_explode(
+            "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+        );
+        test_explode(
+            "[[[[4,0],[5,0]],[[[4,5],[2,6]],[9,5]]],[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]]",
+            "[[[[4,0],[5,4]],[[0,[7,6]],[9,5]]],[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]]",
+        );
+
+        let mut shallow = number("[[[[0,9],2],3],4]");
+        assert_eq!(shallow.explode(), None);
+    }
+
+    #[test]
+    fn split() {
+        for (src, expected) in [
+            ("[1,2]", "[1,2]"),
+            ("[15,5]", "[[7,8],5]"),
+            ("[15,15]", "[[7,8],15]"),
+        ] {
+            let mut src = number(src);
+            src.split();
+            assert_eq!(src, number(expected));
+        }
+    }
+
+    #[test]
+    fn reduction() {
+        let sum = SnailfishNumber {
+            elements: number("[[[[4,3],4],4],[7,[[8,4],9]]]")
+                .elements
+                .into_iter()
+                .chain(number("[1,1]").elements)
+                .map(|(value, depth)| (value, depth + 1))
+                .collect(),
+        };
+        assert_eq!(sum.to_string(), "[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
+
+        let mut reduction = sum.reduction();
+        let mut steps = Vec::new();
+        while let Some(step) = reduction.next() {
+            steps.push(format!("{}: {}", step, reduction.number()));
+        }
+        assert_eq!(
+            steps,
+            vec![
+                "explode [4,3]: [[[[0,7],4],[7,[[8,4],9]]],[1,1]]",
+                "explode [8,4]: [[[[0,7],4],[15,[0,13]]],[1,1]]",
+                "split 15: [[[[0,7],4],[[7,8],[0,13]]],[1,1]]",
+                "split 13: [[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]",
+                "explode [6,7]: [[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
+            ]
+        );
+        assert_eq!(
+            reduction.into_number(),
+            number("[[[[4,3],4],4],[7,[[8,4],9]]]") + number("[1,1]")
+        );
+    }
+
+    #[test]
+    fn sum() {
+        let numbers = ["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]", "[6,6]"]
+            .map(number)
+            .to_vec();
+        let sum: Option<SnailfishNumber> = numbers.iter().sum();
+        assert_eq!(sum.unwrap().to_string(), "[[[[5,0],[7,4]],[5,5]],[6,6]]");
+        let sum: Option<SnailfishNumber> = numbers[..4].iter().cloned().sum();
+        assert_eq!(sum.unwrap().to_string(), "[[[[1,1],[2,2]],[3,3]],[4,4]]");
+        assert_eq!(
+            Vec::<SnailfishNumber>::new().into_iter().sum::<Option<_>>(),
+            None
+        );
+    }
+
+    #[test]
+    fn magnitude() {
+        for (s, expected) in [
+            ("9", 9),
+            ("[9,1]", 29),
+            ("[[1,2],[[3,4],5]]", 143),
+            ("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", 1384),
+            ("[[[[1,1],[2,2]],[3,3]],[4,4]]", 445),
+            ("[[[[3,0],[5,3]],[4,4]],[5,5]]", 791),
+            ("[[[[5,0],[7,4]],[5,5]],[6,6]]", 1137),
+            (
+                "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+                3488,
+            ),
+        ] {
+            assert_eq!(number(s).magnitude(), expected, "{}", s);
+        }
+    }
+
+    #[test]
+    fn largest_sum() {
+        let numbers = ["[[1,2],3]", "[9,[8,7]]"].map(number);
+        assert_eq!(
+            largest_sum_magnitude(&numbers),
+            [
+                (numbers[0].clone() + &numbers[1]).magnitude(),
+                (numbers[1].clone() + &numbers[0]).magnitude()
+            ]
+            .into_iter()
+            .max()
+        );
+        assert_eq!(largest_sum_magnitude(&numbers[..1]), None);
+    }
+}
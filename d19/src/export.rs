@@ -0,0 +1,233 @@
+//! Export of an [`Alignment`]: the beacon map and the scanners' placements as
+//! CSV or ASCII PLY, for spreadsheets and point-cloud viewers, and the pairs of
+//! scanners tried during alignment as a JSON graph.
+use crate::registration::Alignment;
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// PLY colours for beacons and scanners.
+const BEACON_COLOUR: [u8; 3] = [255, 255, 255];
+const SCANNER_COLOUR: [u8; 3] = [255, 0, 0];
+
+/// Every beacon, relative to the first scanner, one `x,y,z` row each.
+pub fn beacons_csv<W: Write>(alignment: &Alignment, mut out: W) -> io::Result<()> {
+    writeln!(out, "x,y,z")?;
+    for beacon in alignment.beacons() {
+        writeln!(out, "{},{},{}", beacon.x, beacon.y, beacon.z)?;
+    }
+    Ok(())
+}
+
+/// Each scanner's position and rotation matrix, relative to the first, and
+/// the scanner it was aligned against.
+pub fn scanners_csv<W: Write>(alignment: &Alignment, mut out: W) -> io::Result<()> {
+    writeln!(
+        out,
+        "name,x,y,z,r11,r12,r13,r21,r22,r23,r31,r32,r33,aligned_to"
+    )?;
+    for (scan, placement) in alignment.scans().iter().zip(alignment.placements()) {
+        let position = placement.position;
+        let rotation = placement.rotation.rows().concat();
+        let parent = placement.parent.map_or(String::new(), |parent| {
+            quote(&alignment.scans()[parent].name)
+        });
+        writeln!(
+            out,
+            "{},{},{},{},{},{}",
+            quote(&scan.name),
+            position.x,
+            position.y,
+            position.z,
+            rotation
+                .iter()
+                .map(|r| r.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+            parent
+        )?;
+    }
+    Ok(())
+}
+
+/// Quote a CSV field if it needs it.
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// The beacons, in white, and the scanners, in red, as an ASCII PLY point
+/// cloud. Each scanner's rotation is recorded in a comment.
+pub fn ply<W: Write>(alignment: &Alignment, mut out: W) -> io::Result<()> {
+    let beacons = alignment.beacons();
+    let placements = alignment.placements();
+    writeln!(out, "ply")?;
+    writeln!(out, "format ascii 1.0")?;
+    for (scan, placement) in alignment.scans().iter().zip(placements) {
+        writeln!(
+            out,
+            "comment {}: rotation {}",
+            scan.name, placement.rotation
+        )?;
+    }
+    writeln!(out, "element vertex {}", beacons.len() + placements.len())?;
+    for axis in ["x", "y", "z"] {
+        writeln!(out, "property int {}", axis)?;
+    }
+    for channel in ["red", "green", "blue"] {
+        writeln!(out, "property uchar {}", channel)?;
+    }
+    writeln!(out, "end_header")?;
+
+    let points = beacons.iter().map(|beacon| (beacon, BEACON_COLOUR)).chain(
+        placements
+            .iter()
+            .map(|placement| (&placement.position, SCANNER_COLOUR)),
+    );
+    for (point, [red, green, blue]) in points {
+        writeln!(
+            out,
+            "{} {} {} {} {} {}",
+            point.x, point.y, point.z, red, green, blue
+        )?;
+    }
+    Ok(())
+}
+
+/// The scanners, and the pairs of them which the aligner tried, as a graph.
+pub fn graph(alignment: &Alignment) -> Value {
+    let scanners = alignment
+        .scans()
+        .iter()
+        .zip(alignment.placements())
+        .enumerate()
+        .map(|(id, (scan, placement))| {
+            let position = placement.position;
+            json!({
+                "id": id,
+                "name": scan.name,
+                "beacons": scan.beacons.len(),
+                "position": [position.x, position.y, position.z],
+                "rotation": placement.rotation.rows(),
+            })
+        })
+        .collect::<Vec<_>>();
+    let links = alignment
+        .links()
+        .iter()
+        .map(|link| {
+            json!({
+                "from": link.from,
+                "to": link.to,
+                "shared_distances": link.shared_distances,
+                "aligned": link.aligned,
+            })
+        })
+        .collect::<Vec<_>>();
+    json!({ "scanners": scanners, "links": links })
+}
+
+/// Write `beacons.csv`, `scanners.csv`, `map.ply` and `graph.json` to `dir`,
+/// creating it if need be.
+pub fn export(alignment: &Alignment, dir: &Path) -> Result<()> {
+    std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {:?}", dir))?;
+    write_file(&dir.join("beacons.csv"), |out| beacons_csv(alignment, out))?;
+    write_file(&dir.join("scanners.csv"), |out| {
+        scanners_csv(alignment, out)
+    })?;
+    write_file(&dir.join("map.ply"), |out| ply(alignment, out))?;
+    write_file(&dir.join("graph.json"), |mut out| {
+        serde_json::to_writer_pretty(&mut out, &graph(alignment))?;
+        writeln!(out)
+    })
+}
+
+fn write_file(
+    path: &Path,
+    write: impl FnOnce(&mut BufWriter<File>) -> io::Result<()>,
+) -> Result<()> {
+    let file = File::create(path).with_context(|| format!("Failed to create {:?}", path))?;
+    let mut out = BufWriter::new(file);
+    write(&mut out)
+        .and_then(|_| out.flush())
+        .with_context(|| format!("Failed to write {:?}", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registration::{Aligner, Point, Scan};
+
+    /// Two scanners, five apart along x, facing the same way and seeing the
+    /// same three beacons.
+    fn alignment() -> Alignment {
+        let beacons = [
+            Point::new(0, 0, 0),
+            Point::new(1, 0, 0),
+            Point::new(0, 2, 0),
+        ];
+        let offset = Point::new(5, 0, 0);
+        let scans = vec![
+            Scan::new("scanner 0", beacons.to_vec()),
+            Scan::new("scanner, 1", beacons.iter().map(|b| *b - offset).collect()),
+        ];
+        Aligner::new().with_threshold(3).align(scans).unwrap()
+    }
+
+    fn text(write: impl FnOnce(&mut Vec<u8>) -> io::Result<()>) -> String {
+        let mut out = Vec::new();
+        write(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn csv() {
+        let alignment = alignment();
+        assert_eq!(
+            text(|out| beacons_csv(&alignment, out)),
+            "x,y,z\n0,0,0\n0,2,0\n1,0,0\n"
+        );
+        assert_eq!(
+            text(|out| scanners_csv(&alignment, out)),
+            "name,x,y,z,r11,r12,r13,r21,r22,r23,r31,r32,r33,aligned_to\n\
+             scanner 0,0,0,0,1,0,0,0,1,0,0,0,1,\n\
+             \"scanner, 1\",5,0,0,1,0,0,0,1,0,0,0,1,scanner 0\n"
+        );
+    }
+
+    #[test]
+    fn point_cloud() {
+        let ply = text(|out| ply(&alignment(), out));
+        let lines = ply.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "ply");
+        assert_eq!(
+            lines[2],
+            "comment scanner 0: rotation [1 0 0; 0 1 0; 0 0 1]"
+        );
+        assert_eq!(lines[4], "element vertex 5");
+        assert_eq!(lines[11], "end_header");
+        assert_eq!(lines[12], "0 0 0 255 255 255");
+        assert_eq!(lines[16], "5 0 0 255 0 0");
+        assert_eq!(lines.len(), 17);
+    }
+
+    #[test]
+    fn adjacency() {
+        let graph = graph(&alignment());
+        assert_eq!(graph["scanners"][1]["name"], "scanner, 1");
+        assert_eq!(graph["scanners"][1]["position"], json!([5, 0, 0]));
+        assert_eq!(
+            graph["scanners"][0]["rotation"],
+            json!([[1, 0, 0], [0, 1, 0], [0, 0, 1]])
+        );
+        assert_eq!(
+            graph["links"],
+            json!([{"from": 0, "to": 1, "shared_distances": 3, "aligned": true}])
+        );
+    }
+}
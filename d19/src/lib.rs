@@ -0,0 +1,302 @@
+use anyhow::{Context, Result};
+use log::debug;
+use registration::{Aligner, Alignment, Point, Scan};
+use std::io::BufRead;
+use std::path::Path;
+use std::str::FromStr;
+use utils::{ParseError, Solver};
+
+pub mod export;
+pub mod registration;
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut scans = Vec::new();
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(heading) = line.strip_prefix("--- ") {
+                let name = heading.trim_end_matches('-').trim();
+                scans.push(Scan::new(name, Vec::new()));
+            } else {
+                let beacon = Point::from_str(line).map_err(|e| e.with_line(number + 1))?;
+                match scans.last_mut() {
+                    Some(scan) => scan.beacons.push(beacon),
+                    None => {
+                        return Err(ParseError::at(line, line, "a scanner heading")
+                            .with_line(number + 1)
+                            .into())
+                    }
+                }
+            }
+        }
+        Ok(Solution::new(scans))
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        let beacons = self.align()?.beacons();
+        for beacon in &beacons {
+            debug!("{}", beacon);
+        }
+        Ok(beacons.len() as i64)
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        Ok(self.align()?.largest_distance())
+    }
+}
+
+#[derive(Debug)]
+pub struct Solution {
+    scans: Vec<Scan>,
+    aligner: Aligner,
+}
+
+impl Solution {
+    fn new(scans: Vec<Scan>) -> Self {
+        Self {
+            scans,
+            aligner: Aligner::new(),
+        }
+    }
+
+    /// Require scanners to share `threshold` beacons to be aligned, rather
+    /// than 12.
+    pub fn with_threshold(mut self, threshold: usize) -> Self {
+        self.aligner = self.aligner.with_threshold(threshold);
+        self
+    }
+
+    /// Place every scanner relative to the first.
+    pub fn align(&self) -> Result<Alignment> {
+        self.aligner
+            .align(self.scans.clone())
+            .context("Failed to align the scanners")
+    }
+
+    /// Write the beacon map, scanner placements and adjacency graph to `dir`.
+    pub fn export(&self, dir: &Path) -> Result<()> {
+        export::export(&self.align()?, dir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registration::tests::scans;
+    use crate::*;
+
+    #[ctor::ctor]
+    fn init() {
+        env_logger::init();
+    }
+
+    const EXAMPLE: &str = "\
+--- scanner 0 ---
+404,-588,-901
+528,-643,409
+-838,591,734
+390,-675,-793
+-537,-823,-458
+-485,-357,347
+-345,-311,381
+-661,-816,-575
+-876,649,763
+-618,-824,-621
+553,345,-567
+474,580,667
+-447,-329,318
+-584,868,-557
+544,-627,-890
+564,392,-477
+455,729,728
+-892,524,684
+-689,845,-530
+423,-701,434
+7,-33,-71
+630,319,-379
+443,580,662
+-789,900,-551
+459,-707,401
+
+--- scanner 1 ---
+686,422,578
+605,423,415
+515,917,-361
+-336,658,858
+95,138,22
+-476,619,847
+-340,-569,-846
+567,-361,727
+-460,603,-452
+669,-402,600
+729,430,532
+-500,-761,534
+-322,571,750
+-466,-666,-811
+-429,-592,574
+-355,545,-477
+703,-491,-529
+-328,-685,520
+413,935,-424
+-391,539,-444
+586,-435,557
+-364,-763,-893
+807,-499,-711
+755,-354,-619
+553,889,-390
+
+--- scanner 2 ---
+649,640,665
+682,-795,504
+-784,533,-524
+-644,584,-595
+-588,-843,648
+-30,6,44
+-674,560,763
+500,723,-460
+609,671,-379
+-555,-800,653
+-675,-892,-343
+697,-426,-610
+578,704,681
+493,664,-388
+-671,-858,530
+-667,343,800
+571,-461,-707
+-138,-166,112
+-889,563,-600
+646,-828,498
+640,759,510
+-630,509,768
+-681,-892,-333
+673,-379,-804
+-742,-814,-386
+577,-820,562
+
+--- scanner 3 ---
+-589,542,597
+605,-692,669
+-500,565,-823
+-660,373,557
+-458,-679,-417
+-488,449,543
+-626,468,-788
+338,-750,-386
+528,-832,-391
+562,-778,733
+-938,-730,414
+543,643,-506
+-524,371,-870
+407,773,750
+-104,29,83
+378,-903,-323
+-778,-728,485
+426,699,580
+-438,-605,-362
+-469,-447,-387
+509,732,623
+647,635,-688
+-868,-804,481
+614,-800,639
+595,780,-596
+
+--- scanner 4 ---
+727,592,562
+-293,-554,779
+441,611,-461
+-714,465,-776
+-743,427,-804
+-660,-479,-426
+832,-632,460
+927,-485,-438
+408,393,-506
+466,436,-512
+110,16,151
+-258,-428,682
+-393,719,612
+-211,-452,876
+808,-476,-593
+-575,615,604
+-485,667,467
+-680,325,-822
+-627,-443,-432
+872,-547,-609
+833,512,582
+807,604,487
+839,-516,451
+891,-625,532
+-652,-548,-490
+30,-46,-14
+";
+
+    #[test]
+    fn example() {
+        let mut solution = Solution::parse_str(EXAMPLE).unwrap();
+        assert_eq!(solution.part1().unwrap(), 79);
+        assert_eq!(solution.part2().unwrap(), 3621);
+    }
+
+    fn report(scans: &[Scan]) -> String {
+        scans
+            .iter()
+            .map(|scan| {
+                let beacons = scan.beacons.iter().map(|b| format!("{}\n", b));
+                format!("--- {} ---\n{}", scan.name, beacons.collect::<String>())
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn solve() {
+        let (scans, positions, visible) = scans(4);
+        let mut solution = Solution::parse_str(&report(&scans)).unwrap();
+        assert_eq!(solution.scans, scans);
+        assert_eq!(solution.part1().unwrap(), visible.len() as i64);
+        assert_eq!(
+            solution.part2().unwrap(),
+            positions[0].manhattan(&positions[3])
+        );
+    }
+
+    #[test]
+    fn threshold() {
+        let (scans, _, _) = scans(2);
+        let mut solution = Solution::parse_str(&report(&scans))
+            .unwrap()
+            .with_threshold(500);
+        assert_eq!(
+            format!("{:#}", solution.part1().unwrap_err()),
+            "Failed to align the scanners: \
+             could not place scanner 1: none share 500 beacons with a placed scanner"
+        );
+    }
+
+    #[test]
+    fn load_error() {
+        let error = Solution::parse_str("1,2,3\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected a scanner heading, found \"1,2,3\""
+        );
+        let error = Solution::parse_str("--- scanner 0 ---\n1,2\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected a position x,y,z, found \"1\""
+        );
+        let error = Solution::parse_str("--- scanner 0 ---\n1,2,z\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected a coordinate, found \"z\""
+        );
+    }
+}
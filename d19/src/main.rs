@@ -0,0 +1,21 @@
+use anyhow::Result;
+use d19::Solution;
+use log::{debug, info};
+use std::env;
+use std::path::Path;
+use utils::Solver;
+
+fn main() -> Result<()> {
+    env_logger::init();
+
+    let (part, mut solution) = Solution::from_args(19)?;
+    debug!("solution: {:?}", solution);
+    info!("answer is {}", solution.solve(part)?);
+
+    if let Some(dir) = env::var_os("D19_EXPORT") {
+        solution.export(Path::new(&dir))?;
+        info!("exported the map to {:?}", dir);
+    }
+
+    Ok(())
+}
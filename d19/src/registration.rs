@@ -0,0 +1,615 @@
+//! Registration of scanner reports: finding where each scanner is, and which
+//! way it faces, from the beacons it shares with the others.
+//!
+//! Each scanner reports beacon positions relative to itself, in its own,
+//! unknown, orientation. Two scanners which see enough of the same beacons
+//! can be aligned by trying each of the 24 ways a scanner can face, and
+//! finding the translation which makes the most beacons coincide. Aligning
+//! every pair would be slow, so scanners are only compared when the distances
+//! between their beacons, which do not depend on position or orientation,
+//! suggest they overlap.
+use log::debug;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+use utils::{parse_at, ParseError};
+
+/// How many beacons two scanners must share to be aligned, unless told
+/// otherwise.
+pub const DEFAULT_THRESHOLD: usize = 12;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0, z: 0 };
+
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// The square of the straight-line distance to `other`.
+    fn distance_squared(&self, other: &Point) -> i64 {
+        let d = *self - *other;
+        d.x * d.x + d.y * d.y + d.z * d.z
+    }
+
+    fn coordinates(&self) -> [i64; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coordinates = s.split(',').collect::<Vec<_>>();
+        let [x, y, z] = coordinates[..] else {
+            return Err(ParseError::at_start(s, s, "a position x,y,z"));
+        };
+        let x = parse_at(s, x, "a coordinate")?;
+        let y = parse_at(s, y, "a coordinate")?;
+        let z = parse_at(s, z, "a coordinate")?;
+
+        Ok(Self { x, y, z })
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// One of the 24 ways to turn a scanner: a 3×3 matrix with a single 1 or -1
+/// in each row and column, and a determinant of 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation([[i64; 3]; 3]);
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+
+    /// Every proper rotation which maps axes onto axes.
+    pub fn all() -> Vec<Rotation> {
+        const PERMUTATIONS: [[usize; 3]; 6] = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+        let mut rotations = Vec::with_capacity(24);
+        for permutation in PERMUTATIONS {
+            for signs in 0..8 {
+                let mut matrix = [[0; 3]; 3];
+                for (row, column) in permutation.iter().enumerate() {
+                    matrix[row][*column] = if signs & (1 << row) == 0 { 1 } else { -1 };
+                }
+                let rotation = Rotation(matrix);
+                if rotation.determinant() == 1 {
+                    rotations.push(rotation);
+                }
+            }
+        }
+        rotations
+    }
+
+    fn determinant(&self) -> i64 {
+        let m = &self.0;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// The matrix, row by row.
+    pub fn rows(&self) -> [[i64; 3]; 3] {
+        self.0
+    }
+
+    pub fn apply(&self, point: Point) -> Point {
+        let p = point.coordinates();
+        let [x, y, z] = self
+            .0
+            .map(|row| row.iter().zip(p).map(|(a, b)| a * b).sum());
+        Point { x, y, z }
+    }
+
+    /// The rotation which applies `other`, then this one.
+    pub fn after(&self, other: &Rotation) -> Rotation {
+        let mut matrix = [[0; 3]; 3];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = (0..3).map(|k| self.0[i][k] * other.0[k][j]).sum();
+            }
+        }
+        Rotation(matrix)
+    }
+}
+
+impl fmt::Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = self
+            .0
+            .map(|row| format!("{} {} {}", row[0], row[1], row[2]));
+        write!(f, "[{}]", rows.join("; "))
+    }
+}
+
+/// What a scanner reported: the beacons it can see, relative to itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scan {
+    pub name: String,
+    pub beacons: Vec<Point>,
+}
+
+impl Scan {
+    pub fn new(name: impl Into<String>, beacons: Vec<Point>) -> Self {
+        Self {
+            name: name.into(),
+            beacons,
+        }
+    }
+
+    /// The squared distances between every pair of beacons, in order.
+    fn fingerprint(&self) -> Vec<i64> {
+        let mut distances = Vec::new();
+        for (i, a) in self.beacons.iter().enumerate() {
+            for b in &self.beacons[i + 1..] {
+                distances.push(a.distance_squared(b));
+            }
+        }
+        distances.sort_unstable();
+        distances
+    }
+}
+
+/// Where a scanner is, and which way it faces, relative to the first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    pub rotation: Rotation,
+    pub position: Point,
+    /// The scanner it was aligned against, or `None` for the first.
+    pub parent: Option<usize>,
+}
+
+impl Placement {
+    /// Convert `point`, relative to this scanner, to the first scanner's frame.
+    pub fn transform(&self, point: Point) -> Point {
+        self.rotation.apply(point) + self.position
+    }
+}
+
+/// A pair of scanners whose fingerprints suggested they overlap, so the
+/// aligner tried to align the second against the first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Link {
+    pub from: usize,
+    pub to: usize,
+    /// How many distances between beacons the two have in common.
+    pub shared_distances: usize,
+    /// Whether `to` was placed by aligning it against `from`.
+    pub aligned: bool,
+}
+
+/// Every scanner placed relative to the first.
+#[derive(Debug, Clone)]
+pub struct Alignment {
+    scans: Vec<Scan>,
+    placements: Vec<Placement>,
+    links: Vec<Link>,
+}
+
+impl Alignment {
+    pub fn scans(&self) -> &[Scan] {
+        &self.scans
+    }
+
+    /// The placement of each scanner, in the order they were given.
+    pub fn placements(&self) -> &[Placement] {
+        &self.placements
+    }
+
+    /// Each pair of scanners the aligner tried, in the order it tried them.
+    pub fn links(&self) -> &[Link] {
+        &self.links
+    }
+
+    /// Every distinct beacon, relative to the first scanner.
+    pub fn beacons(&self) -> BTreeSet<Point> {
+        self.scans
+            .iter()
+            .zip(&self.placements)
+            .flat_map(|(scan, placement)| {
+                scan.beacons
+                    .iter()
+                    .map(move |beacon| placement.transform(*beacon))
+            })
+            .collect()
+    }
+
+    /// The largest Manhattan distance between two scanners.
+    pub fn largest_distance(&self) -> i64 {
+        let placements = &self.placements;
+        placements
+            .iter()
+            .flat_map(|a| placements.iter().map(|b| a.position.manhattan(&b.position)))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Why scanners could not be aligned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlignError {
+    NoScanners,
+    /// These scanners could not be linked to the first through enough shared
+    /// beacons.
+    Unplaced {
+        names: Vec<String>,
+        threshold: usize,
+    },
+}
+
+impl fmt::Display for AlignError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlignError::NoScanners => write!(f, "there are no scanners"),
+            AlignError::Unplaced { names, threshold } => write!(
+                f,
+                "could not place {}: none share {} beacons with a placed scanner",
+                names.join(", "),
+                threshold
+            ),
+        }
+    }
+}
+
+impl Error for AlignError {}
+
+/// Aligns scanners which share at least `threshold` beacons.
+#[derive(Debug, Clone)]
+pub struct Aligner {
+    threshold: usize,
+    rotations: Vec<Rotation>,
+}
+
+impl Default for Aligner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Aligner {
+    pub fn new() -> Self {
+        Self {
+            threshold: DEFAULT_THRESHOLD,
+            rotations: Rotation::all(),
+        }
+    }
+
+    pub fn with_threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Place every scanner relative to the first, working outwards from it.
+    pub fn align(&self, scans: Vec<Scan>) -> Result<Alignment, AlignError> {
+        if scans.is_empty() {
+            return Err(AlignError::NoScanners);
+        }
+        let fingerprints = scans.iter().map(Scan::fingerprint).collect::<Vec<_>>();
+        // `threshold` shared beacons give this many shared distances.
+        let shared_distances = self.threshold * self.threshold.saturating_sub(1) / 2;
+
+        let mut placements: Vec<Option<Placement>> = vec![None; scans.len()];
+        placements[0] = Some(Placement {
+            rotation: Rotation::IDENTITY,
+            position: Point::ORIGIN,
+            parent: None,
+        });
+        let mut links = Vec::new();
+        let mut queue = VecDeque::from([0]);
+        while let Some(a) = queue.pop_front() {
+            let placed = placements[a].unwrap();
+            for b in 0..scans.len() {
+                if placements[b].is_some() {
+                    continue;
+                }
+                let shared = shared(&fingerprints[a], &fingerprints[b]);
+                if shared < shared_distances {
+                    continue;
+                }
+                let pair = self.pair(&scans[a], &scans[b]);
+                links.push(Link {
+                    from: a,
+                    to: b,
+                    shared_distances: shared,
+                    aligned: pair.is_some(),
+                });
+                if let Some((rotation, position)) = pair {
+                    debug!(
+                        "{} is at {} relative to {}, turned {}",
+                        scans[b].name, position, scans[a].name, rotation
+                    );
+                    placements[b] = Some(Placement {
+                        rotation: placed.rotation.after(&rotation),
+                        position: placed.transform(position),
+                        parent: Some(a),
+                    });
+                    queue.push_back(b);
+                }
+            }
+        }
+
+        let names = scans
+            .iter()
+            .zip(&placements)
+            .filter(|(_, placement)| placement.is_none())
+            .map(|(scan, _)| scan.name.clone())
+            .collect::<Vec<_>>();
+        if !names.is_empty() {
+            return Err(AlignError::Unplaced {
+                names,
+                threshold: self.threshold,
+            });
+        }
+        Ok(Alignment {
+            scans,
+            placements: placements.into_iter().flatten().collect(),
+            links,
+        })
+    }
+
+    /// How to turn and move `b` so that at least `threshold` of its beacons
+    /// land on `a`'s, if there is a way.
+    fn pair(&self, a: &Scan, b: &Scan) -> Option<(Rotation, Point)> {
+        let mut votes = HashMap::new();
+        for rotation in &self.rotations {
+            votes.clear();
+            for beacon in &b.beacons {
+                let turned = rotation.apply(*beacon);
+                for target in &a.beacons {
+                    let count = votes.entry(*target - turned).or_insert(0);
+                    *count += 1;
+                    if *count >= self.threshold {
+                        return Some((*rotation, *target - turned));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+/// How many values two sorted lists have in common.
+fn shared(a: &[i64], b: &[i64]) -> usize {
+    let (mut i, mut j, mut count) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                count += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn points(input: &[&str]) -> Vec<Point> {
+        input.iter().map(|p| Point::from_str(p).unwrap()).collect()
+    }
+
+    /// A deterministic stream of pseudo-random numbers.
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next(&mut self, range: i64) -> i64 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((self.0 >> 33) % (2 * range as u64 + 1)) as i64 - range
+        }
+    }
+
+    /// Scanners spaced along the x axis from the origin, each turned a
+    /// different way, and each seeing the beacons within 1000 of it on every
+    /// axis. Also returns their positions, and every beacon they see.
+    pub(crate) fn scans(count: usize) -> (Vec<Scan>, Vec<Point>, BTreeSet<Point>) {
+        let mut random = Lcg(19);
+        let beacons = (0..40 * count)
+            .map(|_| {
+                Point::new(
+                    random.next(600 * count as i64) + 600 * count as i64 - 500,
+                    random.next(900),
+                    random.next(900),
+                )
+            })
+            .collect::<BTreeSet<_>>();
+        let rotations = Rotation::all();
+        let mut scans = Vec::new();
+        let mut positions = Vec::new();
+        let mut visible = BTreeSet::new();
+        for i in 0..count {
+            let position = match i {
+                0 => Point::ORIGIN,
+                _ => Point::new(1200 * i as i64, random.next(50), random.next(50)),
+            };
+            // Turning back is the inverse, which is the transpose.
+            let rotation = rotations[(i * 7) % 24];
+            let inverse = Rotation(std::array::from_fn(|r| {
+                std::array::from_fn(|c| rotation.0[c][r])
+            }));
+            let seen = beacons
+                .iter()
+                .filter(|b| {
+                    (**b - position)
+                        .coordinates()
+                        .iter()
+                        .all(|c| c.abs() <= 1000)
+                })
+                .collect::<Vec<_>>();
+            visible.extend(seen.iter().copied());
+            let seen = seen.iter().map(|b| inverse.apply(**b - position)).collect();
+            scans.push(Scan::new(format!("scanner {}", i), seen));
+            positions.push(position);
+        }
+        (scans, positions, visible)
+    }
+
+    #[test]
+    fn rotations() {
+        let rotations = Rotation::all();
+        assert_eq!(rotations.len(), 24);
+        assert_eq!(rotations.iter().collect::<HashSet<_>>().len(), 24);
+        assert!(rotations.contains(&Rotation::IDENTITY));
+        for a in &rotations {
+            for b in &rotations {
+                assert!(rotations.contains(&a.after(b)));
+            }
+        }
+        let turned = rotations
+            .iter()
+            .map(|r| r.apply(Point::new(1, 2, 3)))
+            .collect::<HashSet<_>>();
+        assert_eq!(turned.len(), 24);
+        assert!(turned.contains(&Point::new(-2, 1, 3)));
+    }
+
+    #[test]
+    fn orientations() {
+        let original = points(&[
+            "-1,-1,1", "-2,-2,2", "-3,-3,3", "-2,-3,1", "5,6,-4", "8,0,7",
+        ]);
+        for turned in [
+            [
+                "-1,-1,1", "-2,-2,2", "-3,-3,3", "-2,-3,1", "5,6,-4", "8,0,7",
+            ],
+            ["1,-1,1", "2,-2,2", "3,-3,3", "2,-1,3", "-5,4,-6", "-8,-7,0"],
+            [
+                "-1,-1,-1", "-2,-2,-2", "-3,-3,-3", "-1,-3,-2", "4,6,5", "-7,0,8",
+            ],
+            ["1,1,-1", "2,2,-2", "3,3,-3", "1,3,-2", "-4,-6,5", "7,0,8"],
+            ["1,1,1", "2,2,2", "3,3,3", "3,1,2", "-6,-4,-5", "0,7,-8"],
+        ] {
+            let turned = points(&turned);
+            assert!(
+                Rotation::all().iter().any(|r| original
+                    .iter()
+                    .map(|p| r.apply(*p))
+                    .eq(turned.iter().copied())),
+                "{:?}",
+                turned
+            );
+        }
+    }
+
+    #[test]
+    fn fingerprints() {
+        let a = Scan::new("a", points(&["0,0,0", "1,0,0", "0,2,0"]));
+        let b = Scan::new("b", points(&["5,5,5", "5,5,6", "5,3,5"]));
+        assert_eq!(a.fingerprint(), vec![1, 4, 5]);
+        assert_eq!(shared(&a.fingerprint(), &b.fingerprint()), 3);
+        assert_eq!(shared(&[1, 1, 2, 9], &[1, 2, 2, 3]), 2);
+    }
+
+    #[test]
+    fn align() {
+        let (scans, positions, visible) = scans(5);
+        let alignment = Aligner::new().align(scans).unwrap();
+        let placed = alignment
+            .placements()
+            .iter()
+            .map(|p| p.position)
+            .collect::<Vec<_>>();
+        assert_eq!(placed, positions);
+        assert_eq!(alignment.beacons(), visible);
+        assert_eq!(
+            alignment.largest_distance(),
+            positions
+                .iter()
+                .flat_map(|a| positions.iter().map(|b| a.manhattan(b)))
+                .max()
+                .unwrap()
+        );
+        assert_eq!(alignment.placements()[0].parent, None);
+        assert!(alignment.placements()[1..]
+            .iter()
+            .all(|p| p.parent.is_some()));
+        let aligned = alignment
+            .links()
+            .iter()
+            .filter(|link| link.aligned)
+            .map(|link| (link.to, Some(link.from)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            aligned,
+            (1..5)
+                .map(|i| (i, alignment.placements()[i].parent))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn unplaced() {
+        let (mut scans, _, _) = scans(3);
+        scans.push(Scan::new("lost", points(&["0,0,0", "1,1,1"])));
+        assert_eq!(
+            Aligner::new().align(scans.clone()).unwrap_err().to_string(),
+            "could not place lost: none share 12 beacons with a placed scanner"
+        );
+        assert_eq!(
+            Aligner::new()
+                .with_threshold(1000)
+                .align(scans)
+                .unwrap_err(),
+            AlignError::Unplaced {
+                names: vec![
+                    "scanner 1".to_string(),
+                    "scanner 2".to_string(),
+                    "lost".to_string()
+                ],
+                threshold: 1000
+            }
+        );
+        assert_eq!(
+            Aligner::new().align(Vec::new()).unwrap_err(),
+            AlignError::NoScanners
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use anyhow::Result;
+use core::str::FromStr;
+use log::debug;
+use std::io::BufRead;
+use utils::{parse_at, ParseError, Solver};
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut solution = Solution::new();
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            solution.add(Instruction::from_str(&line).map_err(|e| e.with_line(number + 1))?);
+        }
+        Ok(solution)
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        let position = self.instructions.iter().fold((0, 0), steer);
+        debug!("position = {:?}", position);
+        Ok((position.0 * position.1) as i64)
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        let position = self.instructions.iter().fold((0, 0, 0), aim);
+        debug!("position = {:?}", position);
+        Ok((position.0 * position.1) as i64)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Solution {
+    instructions: Vec<Instruction>,
+}
+
+impl Solution {
+    fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    fn add(&mut self, instruction: Instruction) {
+        self.instructions.push(instruction);
+    }
+}
+
+/// Move directly: down and up change the depth.
+fn steer(position: (i32, i32), instruction: &Instruction) -> (i32, i32) {
+    use Instruction::*;
+    debug!("{:?}", instruction);
+    match *instruction {
+        Forward(v) => (position.0 + v, position.1),
+        Down(v) => (position.0, position.1 + v),
+        Up(v) => (position.0, position.1 - v),
+    }
+}
+
+/// Move along the aim, held third: down and up change the aim instead.
+fn aim(position: (i32, i32, i32), instruction: &Instruction) -> (i32, i32, i32) {
+    use Instruction::*;
+    debug!("instruction: {:?}", instruction);
+    match *instruction {
+        Forward(v) => (position.0 + v, position.1 + v * position.2, position.2),
+        Down(v) => (position.0, position.1, position.2 + v),
+        Up(v) => (position.0, position.1, position.2 - v),
+    }
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (command, amount) = s
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| ParseError::end_of_line(s, "an amount"))?;
+        let instruction: fn(i32) -> Self = match command {
+            "forward" => Self::Forward,
+            "down" => Self::Down,
+            "up" => Self::Up,
+            _ => return Err(ParseError::at(s, command, "forward, down or up")),
+        };
+        Ok(instruction(parse_at(s, amount, "an amount")?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unknown_commands() {
+        let error = Solution::parse_str("forward 5\nbackward 3\n").unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.column(), 1);
+        assert_eq!(error.found(), Some("backward"));
+    }
+
+    #[test]
+    fn rejects_bad_amounts() {
+        let error = Instruction::from_str("down x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "column 6: expected an amount, found \"x\""
+        );
+        let error = Instruction::from_str("up").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "column 3: expected an amount, found end of line"
+        );
+    }
+}
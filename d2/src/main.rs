@@ -0,0 +1,14 @@
+use anyhow::Result;
+use d2::Solution;
+use log::info;
+use utils::Solver;
+
+fn main() -> Result<()> {
+    env_logger::init();
+
+    let (part, mut solution) = Solution::from_args(2)?;
+    info!("solution: {:?}", solution);
+    info!("result: {}", solution.solve(part)?);
+
+    Ok(())
+}
@@ -0,0 +1,286 @@
+//! A cellular automaton on an infinite grid of on and off cells, where each
+//! cell's next state is looked up in a table by the 3×3 block around it.
+//!
+//! Only a rectangle of the grid is stored, bit by bit; every cell beyond it is
+//! in the same background state, which the rule moves on like any other cell.
+//! Each generation grows the rectangle by a cell on every side, as far as the
+//! cells at its edge can reach.
+use std::fmt;
+use std::io::{self, Write};
+
+/// The number of 3×3 blocks, and so of entries in a rule.
+pub const BLOCKS: usize = 512;
+
+/// The next state of a cell for each 3×3 block around it, read as a 9-bit
+/// number row by row from the top left, most significant bit first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    table: [u64; BLOCKS / 64],
+}
+
+impl Rule {
+    pub fn from_fn(f: impl Fn(usize) -> bool) -> Self {
+        let mut table = [0; BLOCKS / 64];
+        for block in (0..BLOCKS).filter(|block| f(*block)) {
+            table[block / 64] |= 1 << (block % 64);
+        }
+        Self { table }
+    }
+
+    pub fn next(&self, block: usize) -> bool {
+        self.table[block / 64] & 1 << (block % 64) != 0
+    }
+
+    /// The state of the background after `background`.
+    fn next_background(&self, background: bool) -> bool {
+        self.next(if background { BLOCKS - 1 } else { 0 })
+    }
+
+    /// `grid` and each generation after it.
+    pub fn generations<'a>(&'a self, grid: &BitGrid) -> impl Iterator<Item = BitGrid> + 'a {
+        std::iter::successors(Some(grid.clone()), |grid| Some(grid.step(self)))
+    }
+
+    /// The generation `iterations` after `grid`.
+    pub fn run(&self, grid: &BitGrid, iterations: usize) -> BitGrid {
+        self.generations(grid).nth(iterations).unwrap()
+    }
+}
+
+/// A rectangle of cells, packed a bit per cell and row by row, most
+/// significant bit first, within an infinite background.
+///
+/// Coordinates are `(x, y)`, with `(0, 0)` at the top left of the rectangle,
+/// and may be negative, or beyond it, to reach the background.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    /// Words per row.
+    stride: usize,
+    bits: Vec<u64>,
+    background: bool,
+}
+
+impl BitGrid {
+    /// A `width` by `height` rectangle of cells, all off, in `background`.
+    pub fn new(width: usize, height: usize, background: bool) -> Self {
+        let stride = width.div_ceil(64);
+        Self {
+            width,
+            height,
+            stride,
+            bits: vec![0; stride * height],
+            background,
+        }
+    }
+
+    /// Build a `width` by `height` grid, calling `f(x, y)` for every cell.
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        background: bool,
+        f: impl Fn(usize, usize) -> bool,
+    ) -> Self {
+        let mut grid = Self::new(width, height, background);
+        for y in 0..height {
+            for x in (0..width).filter(|x| f(*x, y)) {
+                grid.set(x, y, true);
+            }
+        }
+        grid
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn background(&self) -> bool {
+        self.background
+    }
+
+    /// The word holding `(x, y)`, and the bit for it.
+    fn locate(&self, x: usize, y: usize) -> (usize, u64) {
+        (y * self.stride + x / 64, 1 << (63 - x % 64))
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> bool {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return self.background;
+        }
+        let (word, bit) = self.locate(x as usize, y as usize);
+        self.bits[word] & bit != 0
+    }
+
+    /// Set a cell in the rectangle. Panics if `(x, y)` is outside it.
+    pub fn set(&mut self, x: usize, y: usize, on: bool) {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is off the grid",
+            x,
+            y
+        );
+        let (word, bit) = self.locate(x, y);
+        if on {
+            self.bits[word] |= bit;
+        } else {
+            self.bits[word] &= !bit;
+        }
+    }
+
+    /// The number of cells which are on, unless the background is.
+    pub fn count(&self) -> Option<usize> {
+        (!self.background).then(|| {
+            self.bits
+                .iter()
+                .map(|word| word.count_ones() as usize)
+                .sum()
+        })
+    }
+
+    /// The next generation, a cell bigger on each side.
+    pub fn step(&self, rule: &Rule) -> Self {
+        let mut next = Self::new(
+            self.width + 2,
+            self.height + 2,
+            rule.next_background(self.background),
+        );
+        for y in 0..next.height {
+            // Cell (x, y) of the next generation is cell (x - 1, y - 1) of this
+            // one; slide its block along the row a column at a time.
+            let centre = y as isize - 1;
+            let column = |block: usize, x: isize| {
+                (block << 1 & 0b110_110_110)
+                    | (self.get(x, centre - 1) as usize) << 6
+                    | (self.get(x, centre) as usize) << 3
+                    | self.get(x, centre + 1) as usize
+            };
+            let mut block = column(column(0, -2), -1);
+            for x in 0..next.width {
+                block = column(block, x as isize);
+                if rule.next(block) {
+                    next.set(x, y, true);
+                }
+            }
+        }
+        next
+    }
+
+    /// Write the rectangle as a binary PBM image, with cells which are on in
+    /// black.
+    pub fn write_pbm<W: Write>(&self, mut out: W) -> io::Result<()> {
+        writeln!(out, "P4")?;
+        let background = if self.background { "on" } else { "off" };
+        writeln!(out, "# background {}", background)?;
+        writeln!(out, "{} {}", self.width, self.height)?;
+        let bytes = self.width.div_ceil(8);
+        for row in self.bits.chunks(self.stride.max(1)).take(self.height) {
+            let row = row
+                .iter()
+                .flat_map(|word| word.to_be_bytes())
+                .take(bytes)
+                .collect::<Vec<_>>();
+            out.write_all(&row)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for BitGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height as isize {
+            for x in 0..self.width as isize {
+                write!(f, "{}", if self.get(x, y) { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Conway's Game of Life: a cell is on next if three cells around it are,
+    /// or if it is and two around it are.
+    fn life() -> Rule {
+        Rule::from_fn(|block| {
+            let centre = block & 0b000_010_000 != 0;
+            let around = (block & !0b000_010_000).count_ones();
+            around == 3 || centre && around == 2
+        })
+    }
+
+    fn grid(rows: &[&str]) -> BitGrid {
+        BitGrid::from_fn(rows[0].len(), rows.len(), false, |x, y| {
+            rows[y].as_bytes()[x] == b'#'
+        })
+    }
+
+    #[test]
+    fn packing() {
+        // Wide enough to span words.
+        let mut grid = BitGrid::new(130, 2, false);
+        for x in [0, 63, 64, 129] {
+            grid.set(x, 1, true);
+        }
+        assert!(grid.get(63, 1) && grid.get(64, 1) && grid.get(129, 1));
+        assert!(!grid.get(62, 1) && !grid.get(129, 0));
+        assert!(!grid.get(-1, 1) && !grid.get(130, 1));
+        assert_eq!(grid.count(), Some(4));
+        grid.set(63, 1, false);
+        assert_eq!(grid.count(), Some(3));
+    }
+
+    #[test]
+    fn blinker() {
+        let rule = life();
+        let start = grid(&[".....", "..#..", "..#..", "..#..", "....."]);
+        let generations = rule.generations(&start).take(3).collect::<Vec<_>>();
+        assert_eq!(
+            generations[1].to_string(),
+            ".......\n\
+             .......\n\
+             .......\n\
+             ..###..\n\
+             .......\n\
+             .......\n\
+             .......\n"
+        );
+        assert_eq!(generations[2].count(), Some(3));
+        assert!(generations[2].get(4, 3) && generations[2].get(4, 5));
+        assert_eq!(rule.run(&start, 2), generations[2]);
+    }
+
+    #[test]
+    fn flashing_background() {
+        // Only cells in empty blocks light up, so the background flashes, and
+        // only the cell in the middle of the dark square it leaves lights up.
+        let rule = Rule::from_fn(|block| block == 0);
+        let start = grid(&["#"]);
+        let first = rule.run(&start, 1);
+        assert!(first.background());
+        assert_eq!(first.count(), None);
+        assert_eq!(first.to_string(), "...\n...\n...\n");
+        let second = rule.run(&start, 2);
+        assert!(!second.background());
+        assert_eq!(second.count(), Some(1));
+        assert!(second.get(2, 2));
+    }
+
+    #[test]
+    fn pbm() {
+        let mut out = Vec::new();
+        grid(&["#.........", "........##"])
+            .write_pbm(&mut out)
+            .unwrap();
+        let header = b"P4\n# background off\n10 2\n";
+        assert_eq!(&out[..header.len()], header);
+        assert_eq!(&out[header.len()..], &[0b1000_0000, 0, 0, 0b1100_0000]);
+    }
+}
@@ -0,0 +1,214 @@
+use anyhow::{Context, Result};
+use automaton::{BitGrid, Rule, BLOCKS};
+use log::debug;
+use std::fs::File;
+use std::io::{BufRead, BufWriter, Write};
+use std::path::Path;
+use utils::{ParseError, Solver};
+
+pub mod automaton;
+
+/// How many times each part enhances the image.
+pub const PASSES: [usize; 2] = [2, 50];
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = usize;
+
+    /// The enhancement algorithm, a line of 512 pixels, then a blank line and
+    /// the image.
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut algorithm = None;
+        let mut rows = Vec::new();
+
+        for (line_no, line) in reader.lines().enumerate() {
+            let raw = line?;
+            let line = raw.trim();
+            check_pixels(&raw, line).map_err(|e| e.with_line(line_no + 1))?;
+
+            if line_no == 0 {
+                if line.len() < BLOCKS {
+                    return Err(ParseError::end_of_line(&raw, "512 pixels")
+                        .with_line(1)
+                        .into());
+                }
+                if line.len() > BLOCKS {
+                    return Err(ParseError::at(&raw, &line[BLOCKS..], "end of line")
+                        .with_line(1)
+                        .into());
+                }
+                let line = line.as_bytes();
+                algorithm = Some(Rule::from_fn(|block| line[block] == b'#'));
+                continue;
+            }
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(first) = rows.first().map(|row: &String| row.len()) {
+                if line.len() < first {
+                    let expected = format!("{} pixels", first);
+                    return Err(ParseError::end_of_line(&raw, expected)
+                        .with_line(line_no + 1)
+                        .into());
+                }
+                if line.len() > first {
+                    return Err(ParseError::at(&raw, &line[first..], "end of line")
+                        .with_line(line_no + 1)
+                        .into());
+                }
+            }
+            rows.push(line.to_string());
+        }
+        let algorithm =
+            algorithm.ok_or_else(|| ParseError::end_of_input("512 pixels").with_line(1))?;
+        let width = rows.first().map_or(0, |row| row.len());
+        let image = BitGrid::from_fn(width, rows.len(), false, |x, y| {
+            rows[y].as_bytes()[x] == b'#'
+        });
+
+        Ok(Solution { algorithm, image })
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        self.lit_after(PASSES[0])
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        self.lit_after(PASSES[1])
+    }
+}
+
+#[derive(Debug)]
+pub struct Solution {
+    algorithm: Rule,
+    image: BitGrid,
+}
+
+impl Solution {
+    /// The image after applying the enhancement algorithm `passes` times.
+    pub fn enhance(&self, passes: usize) -> BitGrid {
+        self.algorithm.run(&self.image, passes)
+    }
+
+    /// The number of pixels lit after `passes` enhancements.
+    pub fn lit_after(&self, passes: usize) -> Result<usize> {
+        let image = self.enhance(passes);
+        debug!("after {} passes:\n{}", passes, image);
+        image
+            .count()
+            .with_context(|| format!("Infinitely many pixels are lit after {} passes", passes))
+    }
+
+    /// Write the image before and after each of `passes` enhancements to
+    /// `dir` as `generation-<n>.pbm`, creating it if need be.
+    pub fn dump(&self, passes: usize, dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {:?}", dir))?;
+        let digits = passes.to_string().len();
+        for (generation, image) in self.algorithm.generations(&self.image).enumerate() {
+            let path = dir.join(format!("generation-{:0digits$}.pbm", generation));
+            let file =
+                File::create(&path).with_context(|| format!("Failed to create {:?}", path))?;
+            let mut out = BufWriter::new(file);
+            image
+                .write_pbm(&mut out)
+                .and_then(|_| out.flush())
+                .with_context(|| format!("Failed to write {:?}", path))?;
+            if generation == passes {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Check that `pixels`, a slice of `line`, is all `#` and `.`.
+fn check_pixels(line: &str, pixels: &str) -> Result<(), ParseError> {
+    match pixels.char_indices().find(|(_, c)| !matches!(c, '#' | '.')) {
+        Some((i, c)) => Err(ParseError::at(
+            line,
+            &pixels[i..i + c.len_utf8()],
+            "'#' or '.'",
+        )),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[ctor::ctor]
+    fn init() {
+        env_logger::init();
+    }
+
+    fn example() -> String {
+        let algorithm =
+            "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..##\
+#..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###\
+.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#.\
+.#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#.....\
+.#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#..\
+...####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.....\
+..##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#";
+        format!("{}\n\n#..#.\n#....\n##..#\n..#..\n..###\n", algorithm)
+    }
+
+    #[test]
+    fn enhance() {
+        let mut solution = Solution::parse_str(&example()).unwrap();
+        assert_eq!(solution.part1().unwrap(), 35);
+        assert_eq!(solution.part2().unwrap(), 3351);
+        assert_eq!(solution.lit_after(0).unwrap(), 10);
+        assert_eq!(solution.enhance(1).width(), 7);
+    }
+
+    #[test]
+    fn infinite() {
+        // With the first pixel of the algorithm lit, the background lights up
+        // after one pass.
+        let input = example().replacen("..", "#.", 1);
+        let solution = Solution::parse_str(&input).unwrap();
+        assert_eq!(
+            solution.lit_after(1).unwrap_err().to_string(),
+            "Infinitely many pixels are lit after 1 passes"
+        );
+    }
+
+    #[test]
+    fn dump() {
+        let solution = Solution::parse_str(&example()).unwrap();
+        let dir = std::env::temp_dir().join(format!("d20-dump-{}", std::process::id()));
+        solution.dump(10, &dir).unwrap();
+        let first = std::fs::read(dir.join("generation-00.pbm")).unwrap();
+        assert_eq!(first, b"P4\n# background off\n5 5\n\x90\x80\xc8\x20\x38");
+        assert!(dir.join("generation-10.pbm").exists());
+        assert!(!dir.join("generation-11.pbm").exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn parse_errors() {
+        let algorithm = example().lines().next().unwrap().to_string();
+        let error = |input: String| Solution::parse_str(&input).unwrap_err().to_string();
+        assert_eq!(
+            error(format!("{}\n\n#..#.\n#...\n", algorithm)),
+            "line 4, column 5: expected 5 pixels, found end of line"
+        );
+        assert_eq!(
+            error(format!("{}\n\n#..#.\n#..#x\n", algorithm)),
+            "line 4, column 5: expected '#' or '.', found \"x\""
+        );
+        assert_eq!(
+            error(format!("{}\n\n#..#.\n#..#..\n", algorithm)),
+            "line 4, column 6: expected end of line, found \".\""
+        );
+        assert_eq!(
+            error(algorithm[..511].to_string()),
+            "line 1, column 512: expected 512 pixels, found end of line"
+        );
+    }
+}
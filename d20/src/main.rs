@@ -0,0 +1,21 @@
+use anyhow::Result;
+use d20::{Solution, PASSES};
+use log::{debug, info};
+use std::env;
+use std::path::Path;
+use utils::Solver;
+
+fn main() -> Result<()> {
+    env_logger::init();
+
+    let (part, mut solution) = Solution::from_args(20)?;
+    debug!("solution: {:?}", solution);
+    info!("answer is {}", solution.solve(part)?);
+
+    if let Some(dir) = env::var_os("D20_DUMP") {
+        solution.dump(PASSES[part as usize - 1], Path::new(&dir))?;
+        info!("dumped each generation to {:?}", dir);
+    }
+
+    Ok(())
+}
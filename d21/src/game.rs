@@ -0,0 +1,358 @@
+//! Dirac Dice, for any number of players, on any track, with any dice.
+//!
+//! Players take turns, each rolling the dice several times, moving their pawn
+//! around a circular track of spaces numbered from 1 by the total, and
+//! scoring the number of the space it lands on. The first to reach the target
+//! score wins.
+//!
+//! Deterministic dice give a single game, which [`Game::play`] plays out.
+//! Quantum dice split the universe on every roll, and [`Game::count_wins`]
+//! counts the universes each player wins in, memoising the count from each
+//! position and set of scores, since many universes pass through the same
+//! ones.
+use anyhow::{bail, ensure, Result};
+use log::{debug, trace};
+use std::collections::HashMap;
+
+/// Dice which roll, or split the universe, the same way whoever rolls them.
+pub trait Dice {
+    /// Roll once, giving each face which comes up and the number of
+    /// universes it comes up in.
+    fn roll(&mut self) -> Vec<(u64, u128)>;
+}
+
+/// Dice which roll 1, 2, 3, ... up to their number of sides, then start again.
+#[derive(Debug, Clone)]
+pub struct DeterministicDice {
+    sides: u64,
+    last: u64,
+}
+
+impl DeterministicDice {
+    pub fn new(sides: u64) -> Self {
+        Self { sides, last: 0 }
+    }
+}
+
+impl Default for DeterministicDice {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+impl Dice for DeterministicDice {
+    fn roll(&mut self) -> Vec<(u64, u128)> {
+        self.last = self.last % self.sides + 1;
+        vec![(self.last, 1)]
+    }
+}
+
+/// Dice which split the universe into one copy for each face, or as many
+/// copies as each face's weight.
+#[derive(Debug, Clone)]
+pub struct QuantumDice {
+    faces: Vec<(u64, u128)>,
+}
+
+impl QuantumDice {
+    /// Dice with faces 1 to `sides`, each coming up in one universe.
+    pub fn new(sides: u64) -> Self {
+        Self::with_faces((1..=sides).map(|face| (face, 1)).collect())
+    }
+
+    /// Dice with the given faces, each coming up in the given number of
+    /// universes.
+    pub fn with_faces(faces: Vec<(u64, u128)>) -> Self {
+        Self { faces }
+    }
+}
+
+impl Default for QuantumDice {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl Dice for QuantumDice {
+    fn roll(&mut self) -> Vec<(u64, u128)> {
+        self.faces.clone()
+    }
+}
+
+/// How a single game ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finish {
+    /// The (0-based) player who won.
+    pub winner: usize,
+    pub scores: Vec<u64>,
+    /// How many times the dice were rolled.
+    pub rolls: u64,
+}
+
+impl Finish {
+    /// The lowest score among the players who lost.
+    pub fn losing_score(&self) -> Option<u64> {
+        self.scores
+            .iter()
+            .enumerate()
+            .filter(|(player, _)| *player != self.winner)
+            .map(|(_, score)| *score)
+            .min()
+    }
+}
+
+/// The rules of a game, and where each player starts.
+#[derive(Debug, Clone)]
+pub struct Game {
+    starts: Vec<u64>,
+    track: u64,
+    target: u64,
+    rolls: usize,
+}
+
+impl Game {
+    /// A game with pawns starting on `starts`, played on a track of 10
+    /// spaces, to 1000 points, rolling three times a turn.
+    pub fn new(starts: Vec<u64>) -> Self {
+        Self {
+            starts,
+            track: 10,
+            target: 1000,
+            rolls: 3,
+        }
+    }
+
+    pub fn with_track(mut self, track: u64) -> Self {
+        self.track = track;
+        self
+    }
+
+    pub fn with_target(mut self, target: u64) -> Self {
+        self.target = target;
+        self
+    }
+
+    pub fn with_rolls(mut self, rolls: usize) -> Self {
+        self.rolls = rolls;
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        ensure!(!self.starts.is_empty(), "There are no players");
+        ensure!(self.track > 0, "The track has no spaces");
+        ensure!(self.rolls > 0, "Players must roll at least once a turn");
+        for (player, start) in self.starts.iter().enumerate() {
+            ensure!(
+                (1..=self.track).contains(start),
+                "Player {} starts on space {}, which is not on the {}-space track",
+                player + 1,
+                start,
+                self.track
+            );
+        }
+        Ok(())
+    }
+
+    /// Where a pawn on `position` lands after moving `total` spaces.
+    fn advance(&self, position: u64, total: u64) -> u64 {
+        (position - 1 + total) % self.track + 1
+    }
+
+    /// Play a single game with dice which never split the universe.
+    pub fn play(&self, dice: &mut impl Dice) -> Result<Finish> {
+        self.validate()?;
+        ensure!(self.target > 0, "Nobody can win with a target of 0");
+        let mut positions = self.starts.clone();
+        let mut scores = vec![0; positions.len()];
+        let mut rolls = 0;
+        for player in (0..positions.len()).cycle() {
+            let mut total = 0;
+            for _ in 0..self.rolls {
+                match dice.roll()[..] {
+                    [(face, _)] => total += face,
+                    _ => bail!("The dice split the universe, so there is no single game"),
+                }
+                rolls += 1;
+            }
+            positions[player] = self.advance(positions[player], total);
+            scores[player] += positions[player];
+            trace!("player {} rolls {}: {:?}", player + 1, total, scores);
+            if scores[player] >= self.target {
+                debug!("player {} wins after {} rolls", player + 1, rolls);
+                return Ok(Finish {
+                    winner: player,
+                    scores,
+                    rolls,
+                });
+            }
+        }
+        unreachable!()
+    }
+
+    /// The number of universes each player wins in.
+    pub fn count_wins(&self, dice: &mut impl Dice) -> Result<Vec<u128>> {
+        self.validate()?;
+        // Combine each turn's rolls into how many universes give each total.
+        let mut totals = HashMap::from([(0, 1)]);
+        for _ in 0..self.rolls {
+            let faces = dice.roll();
+            let mut next = HashMap::new();
+            for (total, universes) in &totals {
+                for (face, copies) in &faces {
+                    *next.entry(total + face).or_insert(0) += universes * copies;
+                }
+            }
+            totals = next;
+        }
+        let mut totals = totals.into_iter().collect::<Vec<_>>();
+        totals.sort_unstable();
+        debug!("totals: {:?}", totals);
+
+        let pawns = self
+            .starts
+            .iter()
+            .map(|start| (*start, 0))
+            .collect::<Vec<_>>();
+        let mut memo = HashMap::new();
+        let wins = self.wins(&pawns, &totals, &mut memo);
+        debug!("{} positions memoised", memo.len());
+        Ok(wins)
+    }
+
+    /// The number of universes in which each player wins, from where `pawns`
+    /// are and what they have scored, with the first player to move next.
+    fn wins(
+        &self,
+        pawns: &[(u64, u64)],
+        totals: &[(u64, u128)],
+        memo: &mut HashMap<Vec<(u64, u64)>, Vec<u128>>,
+    ) -> Vec<u128> {
+        if let Some(wins) = memo.get(pawns) {
+            return wins.clone();
+        }
+        let players = pawns.len();
+        let mut wins = vec![0; players];
+        let (position, score) = pawns[0];
+        for (total, universes) in totals {
+            let position = self.advance(position, *total);
+            let score = score + position;
+            if score >= self.target {
+                wins[0] += universes;
+                continue;
+            }
+            // Rotate so the next player moves first.
+            let mut next = pawns[1..].to_vec();
+            next.push((position, score));
+            for (player, count) in self.wins(&next, totals, memo).iter().enumerate() {
+                wins[(player + 1) % players] += universes * count;
+            }
+        }
+        memo.insert(pawns.to_vec(), wins.clone());
+        wins
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic_dice() {
+        let mut dice = DeterministicDice::new(3);
+        let faces = (0..5).map(|_| dice.roll()[0].0).collect::<Vec<_>>();
+        assert_eq!(faces, vec![1, 2, 3, 1, 2]);
+    }
+
+    #[test]
+    fn practice() {
+        let finish = Game::new(vec![4, 8])
+            .play(&mut DeterministicDice::default())
+            .unwrap();
+        assert_eq!(finish.winner, 0);
+        assert_eq!(finish.scores, vec![1000, 745]);
+        assert_eq!(finish.rolls, 993);
+        assert_eq!(finish.losing_score(), Some(745));
+
+        assert_eq!(
+            Game::new(vec![4, 8])
+                .play(&mut QuantumDice::default())
+                .unwrap_err()
+                .to_string(),
+            "The dice split the universe, so there is no single game"
+        );
+    }
+
+    #[test]
+    fn quantum() {
+        let wins = Game::new(vec![4, 8])
+            .with_target(21)
+            .count_wins(&mut QuantumDice::default())
+            .unwrap();
+        assert_eq!(wins, vec![444356092776315, 341960390180808]);
+    }
+
+    #[test]
+    fn single_universe() {
+        // Quantum dice with one face play the same single game as dice
+        // which always roll it.
+        struct Twos;
+        impl Dice for Twos {
+            fn roll(&mut self) -> Vec<(u64, u128)> {
+                vec![(2, 1)]
+            }
+        }
+        let game = Game::new(vec![1, 5, 6])
+            .with_track(7)
+            .with_target(30)
+            .with_rolls(2);
+        let finish = game.play(&mut Twos).unwrap();
+        let mut wins = vec![0; 3];
+        wins[finish.winner] = 1;
+        assert_eq!(
+            game.count_wins(&mut QuantumDice::with_faces(vec![(2, 1)]))
+                .unwrap(),
+            wins
+        );
+    }
+
+    #[test]
+    fn every_universe() {
+        // Every universe is won by someone: with two-sided dice rolled once a
+        // turn, each of the 2^n ways for the first n turns to go ends a game
+        // at most once.
+        let wins = Game::new(vec![1, 2, 3])
+            .with_track(5)
+            .with_target(6)
+            .with_rolls(1)
+            .count_wins(&mut QuantumDice::new(2))
+            .unwrap();
+        assert!(wins.iter().all(|w| *w > 0));
+
+        // Doubling the weight of every face doubles the universes per roll.
+        let doubled = Game::new(vec![1, 2, 3])
+            .with_track(5)
+            .with_target(6)
+            .with_rolls(1)
+            .count_wins(&mut QuantumDice::with_faces(vec![(1, 2), (2, 2)]))
+            .unwrap();
+        assert!(doubled.iter().zip(&wins).all(|(d, w)| d > w));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Game::new(vec![4, 11])
+                .count_wins(&mut QuantumDice::default())
+                .unwrap_err()
+                .to_string(),
+            "Player 2 starts on space 11, which is not on the 10-space track"
+        );
+        assert_eq!(
+            Game::new(Vec::new())
+                .play(&mut DeterministicDice::default())
+                .unwrap_err()
+                .to_string(),
+            "There are no players"
+        );
+    }
+}
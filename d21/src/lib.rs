@@ -0,0 +1,127 @@
+use anyhow::{ensure, Context, Result};
+use game::{DeterministicDice, Game, QuantumDice};
+use log::debug;
+use regex::Regex;
+use std::io::BufRead;
+use std::str::FromStr;
+use utils::{parse_at, ParseError, Solver};
+
+pub mod game;
+
+/// The score to reach with the quantum dice.
+pub const QUANTUM_TARGET: u64 = 21;
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = u128;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut players = Vec::new();
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let player = Player::from_str(line.trim()).map_err(|e| e.with_line(number + 1))?;
+            players.push(player);
+        }
+        players.sort_by_key(|player| player.id);
+        let mut starts = Vec::new();
+        for (index, player) in players.iter().enumerate() {
+            let id = index as u64 + 1;
+            ensure!(player.id == id, "No starting position for player {}", id);
+            starts.push(player.position);
+        }
+        Ok(Solution { starts })
+    }
+
+    /// The lowest losing score times the number of rolls, in the practice
+    /// game with the deterministic dice.
+    fn part1(&mut self) -> Result<Self::Answer> {
+        let finish = Game::new(self.starts.clone()).play(&mut DeterministicDice::default())?;
+        let losing_score = finish.losing_score().context("There is only one player")?;
+        debug!("losing score {} after {} rolls", losing_score, finish.rolls);
+        Ok(losing_score as u128 * finish.rolls as u128)
+    }
+
+    /// The number of universes in which the luckiest player wins, with the
+    /// quantum dice.
+    fn part2(&mut self) -> Result<Self::Answer> {
+        let wins = Game::new(self.starts.clone())
+            .with_target(QUANTUM_TARGET)
+            .count_wins(&mut QuantumDice::default())?;
+        debug!("wins: {:?}", wins);
+        Ok(wins.into_iter().max().unwrap_or_default())
+    }
+}
+
+#[derive(Debug)]
+pub struct Solution {
+    /// Each player's starting position, in player order.
+    starts: Vec<u64>,
+}
+
+#[derive(Debug, Clone)]
+struct Player {
+    id: u64,
+    position: u64,
+}
+
+impl FromStr for Player {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re =
+            Regex::new(r"^Player (?P<player>\d+) starting position: (?P<position>\d+)$").unwrap();
+        let capt = re.captures(s).ok_or_else(|| {
+            ParseError::at_start(s, s, "Player <n> starting position: <position>")
+        })?;
+        let id = parse_at(s, &capt["player"], "a number")?;
+        let position = parse_at(s, &capt["position"], "a number")?;
+
+        Ok(Self { id, position })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[ctor::ctor]
+    fn init() {
+        env_logger::init();
+    }
+
+    const EXAMPLE: &str = "\
+Player 1 starting position: 4
+Player 2 starting position: 8
+";
+
+    #[test]
+    fn example() {
+        let mut solution = Solution::parse_str(EXAMPLE).unwrap();
+        assert_eq!(solution.part1().unwrap(), 739785);
+        assert_eq!(solution.part2().unwrap(), 444356092776315);
+    }
+
+    #[test]
+    fn players() {
+        let error = |input| Solution::parse_str(input).unwrap_err().to_string();
+        assert_eq!(
+            error("Player 2 starting position: 8\nPlayer 3 starting position: 1\n"),
+            "No starting position for player 1"
+        );
+        assert_eq!(
+            error("Player 1 starting position: 4\nPlayer 2 starts at 8\n"),
+            "line 2, column 1: expected Player <n> starting position: <position>, found \"P\""
+        );
+        let mut solution = Solution::parse_str("Player 1 starting position: 4\n").unwrap();
+        assert_eq!(
+            solution.part1().unwrap_err().to_string(),
+            "There is only one player"
+        );
+    }
+}
@@ -0,0 +1,411 @@
+//! Sets of cubes on a 3D grid, built from cuboids.
+//!
+//! A [`CuboidSet`] is a list of cuboids, each with a weight, such that the
+//! weights of the cuboids containing a cube add up to 1 if it is in the set
+//! and 0 if it is not. Union, difference and intersection then only need to
+//! add the overlaps of the two sets' cuboids, weighted by inclusion–exclusion.
+//! The list grows quickly that way, so [`CuboidSet::compact`] rebuilds it as
+//! disjoint cuboids of weight 1, by sweeping across each axis in turn and
+//! merging neighbouring slabs which hold the same thing.
+use regex::Regex;
+use std::cmp::{max, min};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use utils::{parse_at, ParseError};
+
+/// The cubes from `min` to `max`, inclusive, along each axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Cuboid {
+    pub min: [i64; 3],
+    pub max: [i64; 3],
+}
+
+impl Cuboid {
+    /// The cuboid with opposite corners `a` and `b`.
+    pub fn new(a: [i64; 3], b: [i64; 3]) -> Self {
+        Self {
+            min: [0, 1, 2].map(|axis| min(a[axis], b[axis])),
+            max: [0, 1, 2].map(|axis| max(a[axis], b[axis])),
+        }
+    }
+
+    pub fn volume(&self) -> u64 {
+        (0..3)
+            .map(|axis| (self.max[axis] - self.min[axis] + 1) as u64)
+            .product()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let cuboid = Self {
+            min: [0, 1, 2].map(|axis| max(self.min[axis], other.min[axis])),
+            max: [0, 1, 2].map(|axis| min(self.max[axis], other.max[axis])),
+        };
+        (0..3)
+            .all(|axis| cuboid.min[axis] <= cuboid.max[axis])
+            .then_some(cuboid)
+    }
+
+    pub fn contains(&self, point: [i64; 3]) -> bool {
+        (0..3).all(|axis| (self.min[axis]..=self.max[axis]).contains(&point[axis]))
+    }
+}
+
+impl fmt::Display for Cuboid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "x={}..{},y={}..{},z={}..{}",
+            self.min[0], self.max[0], self.min[1], self.max[1], self.min[2], self.max[2]
+        )
+    }
+}
+
+impl FromStr for Cuboid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"^x=(-?\d+)\.\.(-?\d+),y=(-?\d+)\.\.(-?\d+),z=(-?\d+)\.\.(-?\d+)$")
+            .unwrap();
+        let capt = re.captures(s).ok_or_else(|| {
+            ParseError::at_start(s, s, "x=<min>..<max>,y=<min>..<max>,z=<min>..<max>")
+        })?;
+        let mut bounds = [0; 6];
+        for (bound, group) in bounds.iter_mut().zip(1..) {
+            *bound = parse_at(s, &capt[group], "a number")?;
+        }
+        let [x1, x2, y1, y2, z1, z2] = bounds;
+        Ok(Self::new([x1, y1, z1], [x2, y2, z2]))
+    }
+}
+
+/// A set of cubes, as weighted cuboids.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CuboidSet {
+    terms: Vec<(Cuboid, i64)>,
+}
+
+impl From<Cuboid> for CuboidSet {
+    fn from(cuboid: Cuboid) -> Self {
+        Self {
+            terms: vec![(cuboid, 1)],
+        }
+    }
+}
+
+impl CuboidSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The weighted cuboids making up the set. After [`CuboidSet::compact`]
+    /// they are disjoint, each of weight 1.
+    pub fn terms(&self) -> &[(Cuboid, i64)] {
+        &self.terms
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut terms = self.terms.clone();
+        terms.extend_from_slice(&other.terms);
+        terms.extend(self.meet(other).map(|(cuboid, weight)| (cuboid, -weight)));
+        Self::tidy(terms)
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut terms = self.terms.clone();
+        terms.extend(self.meet(other).map(|(cuboid, weight)| (cuboid, -weight)));
+        Self::tidy(terms)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::tidy(self.meet(other).collect())
+    }
+
+    /// Add every cube in `cuboid` to the set.
+    pub fn insert(&mut self, cuboid: Cuboid) {
+        *self = self.union(&cuboid.into());
+    }
+
+    /// Take every cube in `cuboid` out of the set.
+    pub fn remove(&mut self, cuboid: Cuboid) {
+        *self = self.difference(&cuboid.into());
+    }
+
+    /// The overlaps of each of this set's cuboids with each of `other`'s.
+    fn meet<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = (Cuboid, i64)> + 'a {
+        self.terms.iter().flat_map(move |(a, a_weight)| {
+            other.terms.iter().filter_map(move |(b, b_weight)| {
+                a.intersection(b)
+                    .map(|cuboid| (cuboid, a_weight * b_weight))
+            })
+        })
+    }
+
+    /// Combine the weights of repeated cuboids, dropping those which cancel.
+    fn tidy(terms: Vec<(Cuboid, i64)>) -> Self {
+        let mut weights = HashMap::new();
+        for (cuboid, weight) in terms {
+            *weights.entry(cuboid).or_insert(0) += weight;
+        }
+        let mut terms = weights
+            .into_iter()
+            .filter(|(_, weight)| *weight != 0)
+            .collect::<Vec<_>>();
+        terms.sort_unstable();
+        Self { terms }
+    }
+
+    /// The number of cubes in the set.
+    pub fn volume(&self) -> u64 {
+        let volume = self
+            .terms
+            .iter()
+            .map(|(cuboid, weight)| *weight as i128 * cuboid.volume() as i128)
+            .sum::<i128>();
+        volume as u64
+    }
+
+    /// The number of cubes in the set within `region`.
+    pub fn volume_within(&self, region: &Cuboid) -> u64 {
+        let volume = self
+            .terms
+            .iter()
+            .filter_map(|(cuboid, weight)| {
+                cuboid
+                    .intersection(region)
+                    .map(|overlap| *weight as i128 * overlap.volume() as i128)
+            })
+            .sum::<i128>();
+        volume as u64
+    }
+
+    pub fn contains(&self, point: [i64; 3]) -> bool {
+        self.terms
+            .iter()
+            .filter(|(cuboid, _)| cuboid.contains(point))
+            .map(|(_, weight)| weight)
+            .sum::<i64>()
+            > 0
+    }
+
+    /// Rebuild the set as disjoint cuboids, each of weight 1.
+    pub fn compact(&mut self) {
+        let mut cuboids = Vec::new();
+        flatten(&slabs(&self.terms, 0), 0, [0; 3], [0; 3], &mut cuboids);
+        self.terms = cuboids.into_iter().map(|cuboid| (cuboid, 1)).collect();
+    }
+}
+
+/// The part of a set between two planes across an axis, from `low` up to but
+/// not including `high`, and what it holds: its slabs across the next axis,
+/// or nothing after the last axis, where a slab is simply full.
+#[derive(Debug, PartialEq, Eq)]
+struct Slab {
+    low: i64,
+    high: i64,
+    inner: Vec<Slab>,
+}
+
+/// The non-empty slabs across `axis` of the set made of `terms`, with
+/// neighbours holding the same thing merged.
+fn slabs(terms: &[(Cuboid, i64)], axis: usize) -> Vec<Slab> {
+    let mut bounds = terms
+        .iter()
+        .flat_map(|(cuboid, _)| [cuboid.min[axis], cuboid.max[axis] + 1])
+        .collect::<Vec<_>>();
+    bounds.sort_unstable();
+    bounds.dedup();
+
+    let mut merged: Vec<Slab> = Vec::new();
+    for window in bounds.windows(2) {
+        let (low, high) = (window[0], window[1]);
+        let across = terms
+            .iter()
+            .filter(|(cuboid, _)| cuboid.min[axis] <= low && high <= cuboid.max[axis] + 1)
+            .copied()
+            .collect::<Vec<_>>();
+        let inner = if axis == 2 {
+            if across.iter().map(|(_, weight)| weight).sum::<i64>() <= 0 {
+                continue;
+            }
+            Vec::new()
+        } else {
+            let inner = slabs(&across, axis + 1);
+            if inner.is_empty() {
+                continue;
+            }
+            inner
+        };
+        match merged.last_mut() {
+            Some(last) if last.high == low && last.inner == inner => last.high = high,
+            _ => merged.push(Slab { low, high, inner }),
+        }
+    }
+    merged
+}
+
+/// Turn slabs across `axis`, within the bounds on earlier axes, back into
+/// cuboids.
+fn flatten(
+    slabs: &[Slab],
+    axis: usize,
+    mut min: [i64; 3],
+    mut max: [i64; 3],
+    cuboids: &mut Vec<Cuboid>,
+) {
+    for slab in slabs {
+        min[axis] = slab.low;
+        max[axis] = slab.high - 1;
+        if axis == 2 {
+            cuboids.push(Cuboid { min, max });
+        } else {
+            flatten(&slab.inner, axis + 1, min, max, cuboids);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn cuboid(s: &str) -> Cuboid {
+        s.parse().unwrap()
+    }
+
+    /// Every cube in the set, found by checking each one in `region`.
+    fn cubes(set: &CuboidSet, region: &Cuboid) -> HashSet<[i64; 3]> {
+        let mut cubes = HashSet::new();
+        for x in region.min[0]..=region.max[0] {
+            for y in region.min[1]..=region.max[1] {
+                for z in region.min[2]..=region.max[2] {
+                    if set.contains([x, y, z]) {
+                        cubes.insert([x, y, z]);
+                    }
+                }
+            }
+        }
+        cubes
+    }
+
+    fn explicit(cuboid: &Cuboid) -> HashSet<[i64; 3]> {
+        cubes(&(*cuboid).into(), cuboid)
+    }
+
+    #[test]
+    fn cuboids() {
+        let a = cuboid("x=10..12,y=10..12,z=10..12");
+        assert_eq!(a.volume(), 27);
+        assert_eq!(a.to_string(), "x=10..12,y=10..12,z=10..12");
+        assert_eq!(cuboid("x=12..10,y=10..12,z=12..10"), a);
+        assert_eq!(
+            a.intersection(&cuboid("x=11..13,y=11..13,z=11..13")),
+            Some(cuboid("x=11..12,y=11..12,z=11..12"))
+        );
+        assert_eq!(a.intersection(&cuboid("x=13..13,y=10..12,z=10..12")), None);
+        assert!(a.contains([10, 11, 12]));
+        assert!(!a.contains([10, 11, 13]));
+        assert_eq!(
+            "x=1..2,y=3..4".parse::<Cuboid>().unwrap_err().to_string(),
+            "column 1: expected x=<min>..<max>,y=<min>..<max>,z=<min>..<max>, found \"x\""
+        );
+    }
+
+    #[test]
+    fn reboot() {
+        let mut set = CuboidSet::new();
+        set.insert(cuboid("x=10..12,y=10..12,z=10..12"));
+        set.insert(cuboid("x=11..13,y=11..13,z=11..13"));
+        set.remove(cuboid("x=9..11,y=9..11,z=9..11"));
+        set.insert(cuboid("x=10..10,y=10..10,z=10..10"));
+        assert_eq!(set.volume(), 39);
+        assert_eq!(set.volume_within(&cuboid("x=13..20,y=0..20,z=0..20")), 9);
+        assert!(set.contains([10, 10, 10]));
+        assert!(!set.contains([11, 11, 11]));
+
+        let before = cubes(&set, &cuboid("x=8..14,y=8..14,z=8..14"));
+        set.compact();
+        assert_eq!(cubes(&set, &cuboid("x=8..14,y=8..14,z=8..14")), before);
+        assert_eq!(set.volume(), 39);
+        assert!(set.terms().iter().all(|(_, weight)| *weight == 1));
+        let volumes = set.terms().iter().map(|(c, _)| c.volume()).sum::<u64>();
+        assert_eq!(volumes, 39);
+    }
+
+    #[test]
+    fn compaction_merges() {
+        // Two halves of a cuboid, and two overlapping parts of it, each
+        // compact to the whole cuboid.
+        let whole = cuboid("x=0..9,y=0..4,z=0..2");
+        let mut halves = CuboidSet::from(cuboid("x=0..4,y=0..4,z=0..2"));
+        halves.insert(cuboid("x=5..9,y=0..4,z=0..2"));
+        halves.compact();
+        assert_eq!(halves.terms(), &[(whole, 1)]);
+
+        let mut overlapping = CuboidSet::from(cuboid("x=0..6,y=0..4,z=0..2"));
+        overlapping.insert(cuboid("x=3..9,y=0..4,z=0..2"));
+        assert_eq!(overlapping.terms().len(), 3);
+        overlapping.compact();
+        assert_eq!(overlapping.terms(), &[(whole, 1)]);
+
+        let mut empty = CuboidSet::from(whole).difference(&whole.into());
+        empty.compact();
+        assert_eq!(empty, CuboidSet::new());
+    }
+
+    /// A small random cuboid near the origin, from a linear congruential
+    /// generator.
+    fn random_cuboid(seed: &mut u64) -> Cuboid {
+        let mut random = |range: i64| {
+            *seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (*seed >> 33) as i64 % range
+        };
+        let a = [random(10), random(10), random(10)];
+        let size = random(4);
+        Cuboid::new(a, a.map(|c| c + size))
+    }
+
+    /// A set built by adding and taking out random cuboids, and its cubes.
+    fn random_set(seed: &mut u64) -> (CuboidSet, HashSet<[i64; 3]>) {
+        let mut set = CuboidSet::new();
+        let mut cubes = HashSet::new();
+        for step in 0..8 {
+            let cuboid = random_cuboid(seed);
+            if step % 3 == 2 {
+                set.remove(cuboid);
+                cubes = &cubes - &explicit(&cuboid);
+            } else {
+                set.insert(cuboid);
+                cubes.extend(explicit(&cuboid));
+            }
+        }
+        (set, cubes)
+    }
+
+    #[test]
+    fn operations() {
+        // Compare random sets with the same sets cube by cube.
+        let mut seed = 22;
+        let region = cuboid("x=0..12,y=0..12,z=0..12");
+        for _ in 0..20 {
+            let (mut a, a_cubes) = random_set(&mut seed);
+            let (b, b_cubes) = random_set(&mut seed);
+            assert_eq!(cubes(&a, &region), a_cubes);
+            assert_eq!(a.volume(), a_cubes.len() as u64);
+            assert_eq!(cubes(&a.union(&b), &region), &a_cubes | &b_cubes);
+            assert_eq!(cubes(&a.difference(&b), &region), &a_cubes - &b_cubes);
+            assert_eq!(cubes(&a.intersection(&b), &region), &a_cubes & &b_cubes);
+
+            let within = random_cuboid(&mut seed);
+            let inside = a_cubes.iter().filter(|c| within.contains(**c)).count();
+            assert_eq!(a.volume_within(&within), inside as u64);
+
+            a.compact();
+            assert_eq!(cubes(&a, &region), a_cubes);
+            let volumes = a.terms().iter().map(|(c, _)| c.volume()).sum::<u64>();
+            assert_eq!(volumes, a_cubes.len() as u64);
+        }
+    }
+}
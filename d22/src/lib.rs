@@ -0,0 +1,191 @@
+use anyhow::Result;
+use cuboid::{Cuboid, CuboidSet};
+use log::debug;
+use regex::Regex;
+use std::cmp::max;
+use std::io::BufRead;
+use std::str::FromStr;
+use utils::{ParseError, Solver};
+
+pub mod cuboid;
+
+/// The cubes considered while initialising the reactor.
+pub const INITIALISATION: Cuboid = Cuboid {
+    min: [-50; 3],
+    max: [50; 3],
+};
+
+/// How many cuboids the reactor may be made of before trying to compact it.
+const COMPACTION_THRESHOLD: usize = 128;
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = u64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut solution = Solution::new();
+        for (number, line) in reader.lines().enumerate() {
+            let step = Step::from_str(line?.trim()).map_err(|e| e.with_line(number + 1))?;
+            solution.add(step);
+        }
+        Ok(solution)
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        Ok(self.reboot().volume_within(&INITIALISATION))
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        Ok(self.reboot().volume())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Solution {
+    steps: Vec<Step>,
+}
+
+impl Solution {
+    fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    fn add(&mut self, step: Step) {
+        self.steps.push(step);
+    }
+
+    /// The cubes left on after every step.
+    ///
+    /// Whenever the reactor has grown to twice its size after the last try, or
+    /// past the threshold, it is compacted, if that leaves it made of fewer
+    /// cuboids. Overlapping steps can often be described more briefly by their
+    /// weighted overlaps than by disjoint cuboids.
+    pub fn reboot(&self) -> CuboidSet {
+        let mut reactor = CuboidSet::new();
+        let mut limit = COMPACTION_THRESHOLD;
+        for step in &self.steps {
+            match step.mode {
+                Mode::On => reactor.insert(step.cuboid),
+                Mode::Off => reactor.remove(step.cuboid),
+            }
+            if reactor.terms().len() > limit {
+                let mut compacted = reactor.clone();
+                compacted.compact();
+                debug!(
+                    "compacting {} cuboids gives {}",
+                    reactor.terms().len(),
+                    compacted.terms().len()
+                );
+                if compacted.terms().len() < reactor.terms().len() {
+                    reactor = compacted;
+                }
+                limit = max(2 * reactor.terms().len(), COMPACTION_THRESHOLD);
+            }
+        }
+        debug!("{} cuboids", reactor.terms().len());
+        reactor
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    On,
+    Off,
+}
+
+impl FromStr for Mode {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "on" => Ok(Self::On),
+            "off" => Ok(Self::Off),
+            _ => Err(ParseError::at(s, s, "on or off")),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Step {
+    mode: Mode,
+    cuboid: Cuboid,
+}
+
+impl FromStr for Step {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        debug!("line: {}", s);
+        let re = Regex::new(r"^(?P<mode>[^\s]+) (?P<cuboid>[^\s]+)$").unwrap();
+        let capt = re.captures(s).ok_or_else(|| {
+            ParseError::at_start(s, s, "on|off x=<min>..<max>,y=<min>..<max>,z=<min>..<max>")
+        })?;
+        let mode = &capt["mode"];
+        let mode = Mode::from_str(mode).map_err(|e| e.within(s, mode))?;
+        let cuboid = &capt["cuboid"];
+        let cuboid = Cuboid::from_str(cuboid).map_err(|e| e.within(s, cuboid))?;
+        Ok(Self { mode, cuboid })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[ctor::ctor]
+    fn init() {
+        env_logger::init();
+    }
+
+    const EXAMPLE: &str = "\
+on x=10..12,y=10..12,z=10..12
+on x=11..13,y=11..13,z=11..13
+off x=9..11,y=9..11,z=9..11
+on x=10..10,y=10..10,z=10..10
+";
+
+    #[test]
+    fn example() {
+        let mut solution = Solution::parse_str(EXAMPLE).unwrap();
+        assert_eq!(solution.part1().unwrap(), 39);
+        assert_eq!(solution.part2().unwrap(), 39);
+    }
+
+    #[test]
+    fn outside_initialisation() {
+        let input = format!("{}on x=40..60,y=0..0,z=0..0\n", EXAMPLE);
+        let mut solution = Solution::parse_str(&input).unwrap();
+        assert_eq!(solution.part1().unwrap(), 39 + 11);
+        assert_eq!(solution.part2().unwrap(), 39 + 21);
+    }
+
+    #[test]
+    fn compaction() {
+        // Side by side slices of a cuboid, which compaction joins up again.
+        let input = (0..300)
+            .map(|x| format!("on x={}..{},y=0..9,z=-5..4\n", x, x))
+            .collect::<String>();
+        let solution = Solution::parse_str(&input).unwrap();
+        let reactor = solution.reboot();
+        assert_eq!(reactor.volume(), 300 * 100);
+        assert!(reactor.terms().len() < COMPACTION_THRESHOLD);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |input| Solution::parse_str(input).unwrap_err().to_string();
+        assert_eq!(
+            error("on x=1..2,y=1..2,z=1..2\nof x=1..2,y=1..2,z=1..2\n"),
+            "line 2, column 1: expected on or off, found \"of\""
+        );
+        assert_eq!(
+            error("on x=1..2,y=1..2,z=1..a\n"),
+            "line 1, column 4: expected x=<min>..<max>,y=<min>..<max>,z=<min>..<max>, found \"x\""
+        );
+    }
+}
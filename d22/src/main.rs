@@ -1,14 +1,14 @@
 use anyhow::Result;
-use d19p1::load;
+use d22::Solution;
 use log::{debug, info};
+use utils::Solver;
 
 fn main() -> Result<()> {
     env_logger::init();
 
-    let mut solution = load("input.d19p1.full")?;
+    let (part, mut solution) = Solution::from_args(22)?;
     debug!("solution: {:?}", solution);
-    solution.analyse();
-    info!("answer is {}", solution.answer()?);
+    info!("answer is {}", solution.solve(part)?);
 
     Ok(())
 }
@@ -0,0 +1,866 @@
+//! Amphipod burrows of any shape, and the cheapest way to organise them.
+//!
+//! A burrow is a hallway with rooms below it, all drawn as a diagram:
+//!
+//! ```text
+//! #############
+//! #...........#
+//! ###B#C#B#D###
+//!   #A#D#C#A#
+//!   #########
+//! ```
+//!
+//! Rooms may be any depth, and there may be any number of them, up to seven,
+//! with amphipods `A`, `B`, ... belonging in the first, second, ... room.
+//! Each space holds nothing or one amphipod, in three bits, so a whole burrow
+//! packs into one [`State`].
+use anyhow::{bail, Context, Result};
+use log::debug;
+use std::fmt;
+use utils::{search, ParseError};
+
+/// Each space takes three bits of a [`State`].
+const BITS: usize = 3;
+
+/// The most spaces a burrow can have.
+pub const MAX_SPACES: usize = 128 / BITS;
+
+/// The most rooms, and so kinds of amphipod, a burrow can have.
+pub const MAX_ROOMS: usize = (1 << BITS) - 1;
+
+/// Which amphipod, if any, is in each space of a burrow: first the hallway,
+/// left to right, then each room in turn, top to bottom.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct State(u128);
+
+impl State {
+    /// The kind of amphipod in `space`, counting `A` as 0.
+    pub fn get(&self, space: usize) -> Option<usize> {
+        match (self.0 >> (space * BITS)) & ((1 << BITS) - 1) {
+            0 => None,
+            v => Some(v as usize - 1),
+        }
+    }
+
+    fn set(&mut self, space: usize, amphipod: Option<usize>) {
+        let shift = space * BITS;
+        let value = amphipod.map_or(0, |kind| kind as u128 + 1);
+        self.0 = (self.0 & !(((1 << BITS) - 1) << shift)) | (value << shift);
+    }
+
+    /// The state after `step`.
+    fn after(&self, step: &Step) -> State {
+        let mut next = *self;
+        next.set(step.to, self.get(step.from));
+        next.set(step.from, None);
+        next
+    }
+}
+
+/// The energy an amphipod of `kind` uses to move one space.
+pub fn energy(kind: usize) -> u64 {
+    10_u64.pow(kind as u32)
+}
+
+fn amphipod_char(kind: usize) -> char {
+    (b'A' + kind as u8) as char
+}
+
+/// One amphipod moving from one space to another, in `distance` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Step {
+    from: usize,
+    to: usize,
+    distance: usize,
+}
+
+/// The shape of a burrow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    /// The diagram column of the leftmost hallway space.
+    left: usize,
+    hallway: usize,
+    /// The hallway space above each room.
+    doors: Vec<usize>,
+    depth: usize,
+}
+
+impl Layout {
+    pub fn hallway(&self) -> usize {
+        self.hallway
+    }
+
+    pub fn rooms(&self) -> usize {
+        self.doors.len()
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn spaces(&self) -> usize {
+        self.hallway + self.rooms() * self.depth
+    }
+
+    /// The space `slot` places down into `room`, counting the top as 0.
+    pub fn room_space(&self, room: usize, slot: usize) -> usize {
+        self.hallway + room * self.depth + slot
+    }
+
+    /// Where `space` is drawn in the diagram, as a (0-based) column and row.
+    pub fn position(&self, space: usize) -> (usize, usize) {
+        if space < self.hallway {
+            (self.left + space, 1)
+        } else {
+            let room = (space - self.hallway) / self.depth;
+            let slot = (space - self.hallway) % self.depth;
+            (self.left + self.doors[room], 2 + slot)
+        }
+    }
+
+    /// The move taking `before` to `after`, which differ by one amphipod.
+    fn move_between(&self, before: &State, after: &State) -> Move {
+        let changed = |was: bool| {
+            (0..self.spaces())
+                .find(|space| {
+                    before.get(*space).is_some() == was && after.get(*space).is_some() != was
+                })
+                .unwrap()
+        };
+        let (from, to) = (changed(true), changed(false));
+        let kind = before.get(from).unwrap();
+        let (from, to) = (self.position(from), self.position(to));
+        // Up to the hallway, along it, and down again.
+        let distance = (from.1 - 1) + from.0.abs_diff(to.0) + (to.1 - 1);
+        Move {
+            amphipod: amphipod_char(kind),
+            from,
+            to,
+            distance,
+            energy: distance as u64 * energy(kind),
+        }
+    }
+
+    fn is_door(&self, hallway: usize) -> bool {
+        self.doors.contains(&hallway)
+    }
+
+    /// The burrow once every amphipod is in its own room.
+    fn organised(&self) -> State {
+        let mut state = State::default();
+        for room in 0..self.rooms() {
+            for slot in 0..self.depth {
+                state.set(self.room_space(room, slot), Some(room));
+            }
+        }
+        state
+    }
+
+    /// Whether `room` holds only amphipods which belong there.
+    fn settled(&self, state: &State, room: usize) -> bool {
+        (0..self.depth).all(|slot| {
+            state
+                .get(self.room_space(room, slot))
+                .is_none_or(|kind| kind == room)
+        })
+    }
+
+    /// The topmost occupied slot of `room`.
+    fn top(&self, state: &State, room: usize) -> Option<usize> {
+        (0..self.depth).find(|slot| state.get(self.room_space(room, *slot)).is_some())
+    }
+
+    /// The slot an amphipod entering `room` would stop in, if it may enter.
+    fn entry(&self, state: &State, room: usize) -> Option<usize> {
+        if !self.settled(state, room) {
+            return None;
+        }
+        self.top(state, room).unwrap_or(self.depth).checked_sub(1)
+    }
+
+    /// Whether the hallway is empty from `from`, exclusive, to `to`, inclusive.
+    fn clear(&self, state: &State, from: usize, to: usize) -> bool {
+        let mut spaces = if from < to {
+            from + 1..to + 1
+        } else {
+            to..from
+        };
+        spaces.all(|space| state.get(space).is_none())
+    }
+
+    /// Every move an amphipod can make from `state`. Moving an amphipod into
+    /// its own room is never a mistake, so if one can, that is the only move.
+    fn steps(&self, state: &State) -> Vec<Step> {
+        for space in 0..self.hallway {
+            if let Some(kind) = state.get(space) {
+                let door = self.doors[kind];
+                if let Some(slot) = self.entry(state, kind) {
+                    if self.clear(state, space, door) {
+                        return vec![Step {
+                            from: space,
+                            to: self.room_space(kind, slot),
+                            distance: space.abs_diff(door) + slot + 1,
+                        }];
+                    }
+                }
+            }
+        }
+
+        let mut steps = Vec::new();
+        for room in 0..self.rooms() {
+            if self.settled(state, room) {
+                continue;
+            }
+            let slot = match self.top(state, room) {
+                Some(slot) => slot,
+                None => continue,
+            };
+            let from = self.room_space(room, slot);
+            let door = self.doors[room];
+            if state.get(door).is_some() {
+                continue;
+            }
+            let kind = state.get(from).unwrap();
+            if let Some(home) = self.entry(state, kind) {
+                let target = self.doors[kind];
+                if self.clear(state, door, target) {
+                    return vec![Step {
+                        from,
+                        to: self.room_space(kind, home),
+                        distance: slot + 1 + door.abs_diff(target) + home + 1,
+                    }];
+                }
+            }
+            let left = (0..door).rev();
+            let right = door + 1..self.hallway;
+            for side in [left.collect::<Vec<_>>(), right.collect()] {
+                for space in side {
+                    if state.get(space).is_some() {
+                        break;
+                    }
+                    if !self.is_door(space) {
+                        steps.push(Step {
+                            from,
+                            to: space,
+                            distance: slot + 1 + door.abs_diff(space),
+                        });
+                    }
+                }
+            }
+        }
+        steps
+    }
+
+    /// A lower bound on the energy needed to organise `state`: each amphipod
+    /// not yet settled must at least reach the top of its room.
+    fn heuristic(&self, state: &State) -> u64 {
+        let mut total = 0;
+        for space in 0..self.hallway {
+            if let Some(kind) = state.get(space) {
+                total += (space.abs_diff(self.doors[kind]) + 1) as u64 * energy(kind);
+            }
+        }
+        for room in 0..self.rooms() {
+            // Once one amphipod in a room is in the wrong place, everything
+            // above it has to leave too.
+            let mut leaving = false;
+            for slot in (0..self.depth).rev() {
+                let kind = match state.get(self.room_space(room, slot)) {
+                    Some(kind) => kind,
+                    None => continue,
+                };
+                leaving |= kind != room;
+                if leaving {
+                    // Out, along to its room, or a space along and back if
+                    // this is its room, then down into the top slot.
+                    let along = match kind == room {
+                        true => 2,
+                        false => self.doors[room].abs_diff(self.doors[kind]),
+                    };
+                    total += (slot + 1 + along + 1) as u64 * energy(kind);
+                }
+            }
+        }
+        total
+    }
+
+    /// Draw `state` as a diagram.
+    pub fn render(&self, state: &State) -> String {
+        let width = self.left + self.hallway + 1;
+        let columns = self
+            .doors
+            .iter()
+            .map(|door| self.left + door)
+            .collect::<Vec<_>>();
+        let first = columns.iter().min().unwrap_or(&self.left) - 1;
+        let last = columns.iter().max().unwrap_or(&self.left) + 1;
+        let space = |space| state.get(space).map_or('.', amphipod_char);
+
+        let mut lines = vec!["#".repeat(width)];
+        let hallway = (0..self.hallway).map(space).collect::<String>();
+        lines.push(format!("{}#{}#", " ".repeat(self.left - 1), hallway));
+        for slot in 0..=self.depth {
+            let (start, end) = if slot == 0 {
+                (0, width)
+            } else {
+                (first, last + 1)
+            };
+            let mut line = " ".repeat(start);
+            for column in start..end {
+                line.push(match columns.iter().position(|c| *c == column) {
+                    Some(room) if slot < self.depth => space(self.room_space(room, slot)),
+                    _ => '#',
+                });
+            }
+            lines.push(line);
+        }
+        lines.join("\n")
+    }
+
+    /// Parse a row of rooms, keeping the amphipod or emptiness in each.
+    fn parse_row(&self, line: &str) -> Result<Vec<Option<usize>>, ParseError> {
+        let line = line.trim_end();
+        let open = open_columns(line, self.rooms())?;
+        let mut row = Vec::with_capacity(self.rooms());
+        for (room, door) in self.doors.iter().enumerate() {
+            let column = self.left + door;
+            match open.iter().find(|(c, _)| *c == column) {
+                Some((_, amphipod)) => row.push(*amphipod),
+                None if column < line.len() => {
+                    return Err(ParseError::at(
+                        line,
+                        &line[column..column + 1],
+                        format!("room {}", amphipod_char(room)),
+                    ))
+                }
+                None => return Err(ParseError::end_of_line(line, "a room")),
+            }
+        }
+        if let Some((column, _)) = open.iter().find(|(c, _)| !self.is_door(*c - self.left)) {
+            return Err(ParseError::at(line, &line[*column..*column + 1], "a wall"));
+        }
+        Ok(row)
+    }
+}
+
+/// The open spaces in a line of a diagram, by column, along with any
+/// amphipod in them.
+#[allow(clippy::type_complexity)]
+fn open_columns(line: &str, rooms: usize) -> Result<Vec<(usize, Option<usize>)>, ParseError> {
+    let mut open = Vec::new();
+    for (column, c) in line.char_indices() {
+        match c {
+            '#' | ' ' => {}
+            '.' => open.push((column, None)),
+            'A'..='Z' if ((c as u8 - b'A') as usize) < rooms => {
+                open.push((column, Some((c as u8 - b'A') as usize)))
+            }
+            _ => {
+                return Err(ParseError::at(
+                    line,
+                    &line[column..column + c.len_utf8()],
+                    format!(
+                        "a space or an amphipod from A to {}",
+                        amphipod_char(rooms - 1)
+                    ),
+                ))
+            }
+        }
+    }
+    Ok(open)
+}
+
+/// A burrow, and where its amphipods are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Burrow {
+    layout: Layout,
+    state: State,
+}
+
+impl Burrow {
+    /// Parse a diagram: a wall, the hallway, each row of rooms, then the
+    /// bottom wall. Errors give (1-based) line numbers within `lines`.
+    pub fn parse<S: AsRef<str>>(lines: &[S]) -> Result<Self, ParseError> {
+        let mut lines = lines
+            .iter()
+            .map(|line| line.as_ref().trim_end())
+            .enumerate();
+        let line_error = |number: usize| move |e: ParseError| e.with_line(number + 1);
+
+        let (number, top) = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input("a wall").with_line(1))?;
+        if let Some(column) = top.find(|c| c != '#') {
+            return Err(ParseError::at(top, &top[column..], "a wall").with_line(number + 1));
+        }
+
+        let (number, hallway) = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input("the hallway").with_line(2))?;
+        let open = open_columns(hallway, MAX_ROOMS).map_err(line_error(number))?;
+        let (left, last) = match (open.first(), open.last()) {
+            (Some((left, _)), Some((last, _))) if *left > 0 => (*left, *last),
+            _ => return Err(ParseError::at_start(hallway, hallway, "a wall").with_line(2)),
+        };
+        // Walls either side, and only spaces between them.
+        if let Some(column) = (left..=last).find(|c| !open.iter().any(|(o, _)| o == c)) {
+            return Err(
+                ParseError::at(hallway, &hallway[column..column + 1], "a space")
+                    .with_line(number + 1),
+            );
+        }
+        if hallway.len() != last + 2 {
+            return Err(
+                ParseError::at_start(hallway, &hallway[last + 1..], "a wall").with_line(number + 1),
+            );
+        }
+
+        let (number, first) = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input("a row of rooms").with_line(3))?;
+        let doors = open_columns(first, MAX_ROOMS)
+            .map_err(line_error(number))?
+            .iter()
+            .map(|(column, _)| column)
+            .copied()
+            .collect::<Vec<_>>();
+        if let Some(column) = doors.iter().find(|c| !(left..=last).contains(*c)) {
+            return Err(
+                ParseError::at(first, &first[*column..*column + 1], "a wall").with_line(number + 1),
+            );
+        }
+        if doors.is_empty() {
+            return Err(ParseError::at_start(first, first, "a row of rooms").with_line(number + 1));
+        }
+        let mut layout = Layout {
+            left,
+            hallway: last - left + 1,
+            doors: doors.iter().map(|column| column - left).collect(),
+            depth: 0,
+        };
+
+        let mut rows = vec![layout.parse_row(first).map_err(line_error(number))?];
+        let mut bottom = false;
+        for (number, line) in lines.by_ref() {
+            if !line.contains(|c| c != '#' && c != ' ') {
+                bottom = true;
+                break;
+            }
+            rows.push(layout.parse_row(line).map_err(line_error(number))?);
+        }
+        if !bottom {
+            return Err(ParseError::end_of_input("the bottom wall").with_line(rows.len() + 3));
+        }
+        if let Some((number, line)) = lines.find(|(_, line)| !line.is_empty()) {
+            return Err(
+                ParseError::at_start(line, line, "the end of the diagram").with_line(number + 1)
+            );
+        }
+
+        if let Some((column, _)) = open
+            .iter()
+            .find(|(_, amphipod)| amphipod.is_some_and(|kind| kind >= layout.rooms()))
+        {
+            return Err(ParseError::at(
+                hallway,
+                &hallway[*column..*column + 1],
+                format!(
+                    "an amphipod from A to {}",
+                    amphipod_char(layout.rooms() - 1)
+                ),
+            )
+            .with_line(2));
+        }
+
+        layout.depth = rows.len();
+        if layout.spaces() > MAX_SPACES {
+            return Err(ParseError::end_of_input(format!(
+                "at most {} spaces, not {}",
+                MAX_SPACES,
+                layout.spaces()
+            ))
+            .with_line(rows.len() + 3));
+        }
+        let mut state = State::default();
+        for (space, (_, amphipod)) in open.iter().enumerate() {
+            state.set(space, *amphipod);
+        }
+        let burrow = Burrow { layout, state };
+        Ok(burrow.with_rows(&rows))
+    }
+
+    /// The same layout, with the rooms filled from `rows`, top to bottom.
+    fn with_rows(mut self, rows: &[Vec<Option<usize>>]) -> Self {
+        self.layout.depth = rows.len();
+        let hallway = self.layout.hallway;
+        self.state = State(self.state.0 & ((1 << (hallway * BITS)) - 1));
+        for (slot, row) in rows.iter().enumerate() {
+            for (room, amphipod) in row.iter().enumerate() {
+                self.state
+                    .set(self.layout.room_space(room, slot), *amphipod);
+            }
+        }
+        self
+    }
+
+    pub fn layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Parse the extra rows found when unfolding the diagram, which must line
+    /// up with its rooms.
+    pub fn parse_rows<S: AsRef<str>>(
+        &self,
+        lines: &[S],
+    ) -> Result<Vec<Vec<Option<usize>>>, ParseError> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(number, line)| {
+                self.layout
+                    .parse_row(line.as_ref())
+                    .map_err(|e| e.with_line(number + 1))
+            })
+            .collect()
+    }
+
+    /// The burrow with `rows` inserted below the top row of each room.
+    pub fn unfold(&self, rows: &[Vec<Option<usize>>]) -> Result<Self> {
+        let depth = self.layout.depth + rows.len();
+        let spaces = self.layout.hallway + self.layout.rooms() * depth;
+        if spaces > MAX_SPACES {
+            bail!(
+                "Unfolded, the burrow would have {} spaces, but at most {} fit",
+                spaces,
+                MAX_SPACES
+            );
+        }
+        let mut all = vec![self.row(0)];
+        all.extend(rows.iter().cloned());
+        all.extend((1..self.layout.depth).map(|slot| self.row(slot)));
+        Ok(self.clone().with_rows(&all))
+    }
+
+    /// What is in each room, `slot` places down.
+    fn row(&self, slot: usize) -> Vec<Option<usize>> {
+        (0..self.layout.rooms())
+            .map(|room| self.state.get(self.layout.room_space(room, slot)))
+            .collect()
+    }
+
+    /// Find the cheapest way to move every amphipod into its own room.
+    pub fn organise(&self) -> Result<Plan> {
+        let layout = &self.layout;
+        for kind in 0..layout.rooms() {
+            let count = (0..layout.spaces())
+                .filter(|space| self.state.get(*space) == Some(kind))
+                .count();
+            if count != layout.depth {
+                bail!(
+                    "There are {} amphipods of type {}, but the rooms are {} deep",
+                    count,
+                    amphipod_char(kind),
+                    layout.depth
+                );
+            }
+        }
+
+        let goal = layout.organised();
+        let found = search::astar(
+            &self.state,
+            |state| {
+                layout
+                    .steps(state)
+                    .into_iter()
+                    .map(|step| {
+                        let kind = state.get(step.from).unwrap();
+                        (state.after(&step), step.distance as u64 * energy(kind))
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |state| layout.heuristic(state),
+            |state| *state == goal,
+        );
+        let (states, energy) = found.context("The amphipods cannot be organised")?;
+        let moves = states
+            .windows(2)
+            .map(|pair| layout.move_between(&pair[0], &pair[1]))
+            .collect::<Vec<_>>();
+        debug!("organised in {} moves for {} energy", moves.len(), energy);
+        Ok(Plan {
+            layout: layout.clone(),
+            states,
+            moves,
+        })
+    }
+}
+
+/// One amphipod moving, from one (column, row) of the diagram to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub amphipod: char,
+    pub from: (usize, usize),
+    pub to: (usize, usize),
+    pub distance: usize,
+    pub energy: u64,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} from ({}, {}) to ({}, {}): {} steps, {} energy",
+            self.amphipod,
+            self.from.0,
+            self.from.1,
+            self.to.0,
+            self.to.1,
+            self.distance,
+            self.energy
+        )
+    }
+}
+
+/// The cheapest way to organise a burrow, move by move.
+#[derive(Debug, Clone)]
+pub struct Plan {
+    layout: Layout,
+    /// The burrow before the first move, and after each.
+    states: Vec<State>,
+    moves: Vec<Move>,
+}
+
+impl Plan {
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    pub fn states(&self) -> &[State] {
+        &self.states
+    }
+
+    /// The energy used by every move.
+    pub fn energy(&self) -> u64 {
+        self.moves.iter().map(|m| m.energy).sum()
+    }
+
+    /// The diagram of the burrow before the first move, and after each.
+    pub fn frames(&self) -> impl Iterator<Item = String> + '_ {
+        self.states.iter().map(|state| self.layout.render(state))
+    }
+}
+
+/// The whole replay: each move, with the energy used so far, and the burrow
+/// after it.
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut frames = self.frames();
+        writeln!(f, "Start")?;
+        writeln!(f, "{}", frames.next().unwrap_or_default())?;
+        let mut total = 0;
+        for (number, (step, frame)) in self.moves.iter().zip(frames).enumerate() {
+            total += step.energy;
+            writeln!(f)?;
+            writeln!(f, "Move {}: {} (total {})", number + 1, step, total)?;
+            writeln!(f, "{}", frame)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.layout.render(&self.state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########";
+
+    fn burrow(diagram: &str) -> Burrow {
+        Burrow::parse(&diagram.lines().collect::<Vec<_>>()).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let example = burrow(EXAMPLE);
+        assert_eq!(example.layout().hallway(), 11);
+        assert_eq!(example.layout().rooms(), 4);
+        assert_eq!(example.layout().depth(), 2);
+        assert_eq!(example.to_string(), EXAMPLE);
+
+        let narrow = "\
+#######
+#.A...#
+###.#.#
+  #B#A#
+  #####";
+        assert_eq!(burrow(narrow).to_string(), narrow);
+    }
+
+    #[test]
+    fn parse_errors() {
+        for (diagram, error) in [
+            ("", "line 1, column 1: expected a wall, found end of input"),
+            (
+                "#####\n#.x.#",
+                "line 2, column 3: expected a space or an amphipod from A to G, found \"x\"",
+            ),
+            (
+                "#####\n#...#\n##.##\n #E#\n #.#",
+                "line 4, column 3: expected a space or an amphipod from A to A, found \"E\"",
+            ),
+            (
+                "#######\n#.....#\n###.#.#\n  #.#.#",
+                "line 5, column 1: expected the bottom wall, found end of input",
+            ),
+            (
+                "#######\n#.....#\n###.#.#\n  ##..#\n  #####",
+                "line 4, column 4: expected room A, found \"#\"",
+            ),
+            (
+                "#######\n#.....#\n###.#.#\n  #.#.#\n  #####\nx",
+                "line 6, column 1: expected the end of the diagram, found \"x\"",
+            ),
+        ] {
+            assert_eq!(
+                Burrow::parse(&diagram.lines().collect::<Vec<_>>())
+                    .unwrap_err()
+                    .to_string(),
+                error,
+                "{:?}",
+                diagram
+            );
+        }
+    }
+
+    #[test]
+    fn unfold() {
+        let example = burrow(EXAMPLE);
+        let rows = example.parse_rows(&["  #D#C#B#A#", "  #D#B#A#C#"]).unwrap();
+        let unfolded = example.unfold(&rows).unwrap();
+        assert_eq!(
+            unfolded.to_string(),
+            "\
+#############
+#...........#
+###B#C#B#D###
+  #D#C#B#A#
+  #D#B#A#C#
+  #A#D#C#A#
+  #########"
+        );
+        assert_eq!(
+            example.parse_rows(&["  #D#C#B"]).unwrap_err().to_string(),
+            "line 1, column 9: expected a room, found end of line"
+        );
+    }
+
+    #[test]
+    fn organise() {
+        let example = burrow(EXAMPLE);
+        assert_eq!(example.organise().unwrap().energy(), 12521);
+        let rows = example.parse_rows(&["  #D#C#B#A#", "  #D#B#A#C#"]).unwrap();
+        assert_eq!(
+            example.unfold(&rows).unwrap().organise().unwrap().energy(),
+            44169
+        );
+
+        // Any depth, and any number of rooms.
+        let deep = burrow("#########\n#.......#\n###B#A###\n  #A#B#\n  #B#A#\n  #A#B#\n  #####");
+        assert_eq!(deep.to_string().lines().nth(2), Some("###B#A###"));
+        let plan = deep.organise().unwrap();
+        assert_eq!(plan.states().first(), Some(deep.state()));
+        assert_eq!(plan.states().last(), Some(&deep.layout().organised()));
+        assert!(plan.energy() > 0);
+
+        // Three spaces in the hallway are not enough to untangle these.
+        let stuck =
+            burrow("#######\n#.....#\n###B#A#\n  #A#B#\n  #B#A#\n  #A#B#\n  #A#B#\n  #####");
+        assert_eq!(
+            stuck.organise().unwrap_err().to_string(),
+            "The amphipods cannot be organised"
+        );
+
+        let single = burrow("#####\n#...#\n##A##\n #A#\n ###");
+        assert_eq!(single.organise().unwrap().energy(), 0);
+        let missing = burrow("#######\n#.....#\n###A#A#\n  #B#A#\n  #####");
+        assert_eq!(
+            missing.organise().unwrap_err().to_string(),
+            "There are 3 amphipods of type A, but the rooms are 2 deep"
+        );
+    }
+
+    #[test]
+    fn replay() {
+        let plan = burrow("#####\n#A..#\n##.##\n #A#\n ###")
+            .organise()
+            .unwrap();
+        assert_eq!(
+            plan.to_string(),
+            "\
+Start
+#####
+#A..#
+##.##
+ #A#
+ ###
+
+Move 1: A from (1, 1) to (2, 2): 2 steps, 2 energy (total 2)
+#####
+#...#
+##A##
+ #A#
+ ###
+"
+        );
+
+        let example = burrow(EXAMPLE);
+        let plan = example.organise().unwrap();
+        assert_eq!(plan.frames().count(), plan.moves().len() + 1);
+        assert_eq!(plan.frames().next().unwrap(), EXAMPLE);
+        for (m, frames) in plan.moves().iter().zip(plan.states().windows(2)) {
+            let (before, after) = (
+                example.layout().render(&frames[0]),
+                example.layout().render(&frames[1]),
+            );
+            let at = |frame: &str, (column, row): (usize, usize)| {
+                frame.lines().nth(row).unwrap().chars().nth(column).unwrap()
+            };
+            assert_eq!(at(&before, m.from), m.amphipod);
+            assert_eq!(at(&after, m.from), '.');
+            assert_eq!(at(&after, m.to), m.amphipod);
+        }
+        assert_eq!(plan.energy(), 12521);
+    }
+
+    #[test]
+    fn never_enter_unsettled_rooms() {
+        // The B in the hallway must not go into its room while the D is there.
+        for diagram in [
+            "#############\n#...B.......#\n###B#.#C#D###\n  #A#D#C#A#\n  #A#B#C#D#\n  #A#B#C#D#\n  #########",
+            "#############\n#...B.......#\n###.#.#.#.###\n  #.#.#.#.#\n  #B#.#C#D#\n  #A#D#C#A#\n  #########",
+        ] {
+            let burrow = burrow(diagram);
+            let layout = burrow.layout();
+            let steps = layout.steps(burrow.state());
+            assert!(!steps.is_empty());
+            assert!(steps.iter().all(|step| step.from != 3));
+            assert!(steps
+                .iter()
+                .all(|step| !(layout.room_space(1, 0)..layout.room_space(2, 0)).contains(&step.to)));
+        }
+    }
+}
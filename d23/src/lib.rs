@@ -0,0 +1,127 @@
+use anyhow::{Context, Result};
+use burrow::{Burrow, Plan};
+use std::io::BufRead;
+use utils::Solver;
+
+pub mod burrow;
+
+/// The rows hidden by the fold in the diagram, unless the input gives others.
+pub const UNFOLDING: [&str; 2] = ["  #D#C#B#A#", "  #D#B#A#C#"];
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = u64;
+
+    /// The burrow diagram, then optionally a blank line and the rows to insert
+    /// when unfolding it.
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+        let split = lines
+            .iter()
+            .position(|line| line.trim().is_empty())
+            .unwrap_or(lines.len());
+        let burrow = Burrow::parse(&lines[..split])?;
+        let unfolding = lines[split..]
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(number, line)| {
+                burrow
+                    .parse_rows(&[line])
+                    .map(|mut rows| rows.remove(0))
+                    .map_err(|e| e.with_line(split + number + 1))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Solution { burrow, unfolding })
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        Ok(self.plan(false)?.energy())
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        Ok(self.plan(true)?.energy())
+    }
+}
+
+#[derive(Debug)]
+pub struct Solution {
+    burrow: Burrow,
+    /// What is in each room in the rows to insert when unfolding the diagram.
+    unfolding: Vec<Vec<Option<usize>>>,
+}
+
+impl Solution {
+    /// The cheapest way to organise the burrow, as drawn or unfolded.
+    pub fn plan(&self, unfolded: bool) -> Result<Plan> {
+        if !unfolded {
+            return self.burrow.organise();
+        }
+        let rows = if self.unfolding.is_empty() {
+            self.burrow
+                .parse_rows(&UNFOLDING)
+                .context("The usual unfolding does not fit this burrow")?
+        } else {
+            self.unfolding.clone()
+        };
+        self.burrow.unfold(&rows)?.organise()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[ctor::ctor]
+    fn init() {
+        env_logger::init();
+    }
+
+    const EXAMPLE: &str = "\
+#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########
+";
+
+    #[test]
+    fn example() {
+        let mut solution = Solution::parse_str(EXAMPLE).unwrap();
+        assert_eq!(solution.part1().unwrap(), 12521);
+        assert_eq!(solution.part2().unwrap(), 44169);
+        let plan = solution.plan(true).unwrap();
+        assert_eq!(plan.frames().nth(1).unwrap().lines().count(), 7);
+    }
+
+    #[test]
+    fn given_unfolding() {
+        // Unfolding with rows that are already organised changes nothing but
+        // the distance to the bottom of each room.
+        let input = format!("{}\n  #A#B#C#D#\n", EXAMPLE);
+        let mut solution = Solution::parse_str(&input).unwrap();
+        assert_eq!(solution.part1().unwrap(), 12521);
+        assert!(solution.part2().unwrap() > 12521);
+
+        let error = Solution::parse_str(&format!("{}\n  #A#B#C#\n", EXAMPLE)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 7, column 10: expected a room, found end of line"
+        );
+    }
+
+    #[test]
+    fn unusual_burrow() {
+        let mut solution =
+            Solution::parse_str("#######\n#.....#\n###B#A#\n  #A#B#\n  #####\n").unwrap();
+        assert_eq!(solution.part1().unwrap(), 46);
+        assert_eq!(
+            format!("{:#}", solution.part2().unwrap_err()),
+            "The usual unfolding does not fit this burrow: \
+             line 1, column 4: expected a space or an amphipod from A to B, found \"D\""
+        );
+    }
+}
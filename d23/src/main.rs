@@ -0,0 +1,19 @@
+use anyhow::Result;
+use d23::Solution;
+use log::{debug, info};
+use std::env;
+use utils::Solver;
+
+fn main() -> Result<()> {
+    env_logger::init();
+
+    let (part, mut solution) = Solution::from_args(23)?;
+    debug!("solution: {:?}", solution);
+    info!("answer is {}", solution.solve(part)?);
+
+    if env::var_os("D23_REPLAY").is_some() {
+        print!("{}", solution.plan(part == 2)?);
+    }
+
+    Ok(())
+}
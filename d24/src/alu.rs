@@ -0,0 +1,467 @@
+//! The submarine's arithmetic logic unit, and the programs it runs.
+//!
+//! The ALU has four integer registers, `w`, `x`, `y` and `z`, all starting at
+//! zero, and six instructions: `inp a` reads the next input into `a`, and
+//! `add`, `mul`, `div`, `mod` and `eql` combine `a` with a register or a
+//! number `b`, storing the result in `a`.
+use anyhow::{Context, Result};
+use log::trace;
+use std::error::Error;
+use std::fmt;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+use utils::{parse_at, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl Register {
+    pub const ALL: [Register; 4] = [Register::W, Register::X, Register::Y, Register::Z];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operand {
+    Register(Register),
+    Value(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+impl Instruction {
+    /// The register this instruction writes to.
+    pub fn target(&self) -> Register {
+        use Instruction::*;
+        match *self {
+            Inp(a) | Add(a, _) | Mul(a, _) | Div(a, _) | Mod(a, _) | Eql(a, _) => a,
+        }
+    }
+}
+
+/// A loaded program, one instruction per line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Program {
+    instructions: Vec<Instruction>,
+}
+
+impl Program {
+    /// Load a program, reporting the line of any malformed instruction.
+    pub fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut instructions = Vec::new();
+        for (number, line) in reader.lines().enumerate() {
+            let instruction = Instruction::from_str(&line?).map_err(|e| e.with_line(number + 1))?;
+            instructions.push(instruction);
+        }
+        Ok(Self { instructions })
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    /// Run the program to the end on `inputs`.
+    pub fn run(&self, inputs: &[i64]) -> Result<Alu, ExecError> {
+        let mut vm = Vm::new(self, inputs);
+        while vm.step()?.is_some() {}
+        Ok(vm.alu)
+    }
+
+    /// Run the program to the end on `inputs`, writing each instruction and
+    /// the registers after it to `out`.
+    pub fn trace<W: Write>(&self, inputs: &[i64], mut out: W) -> Result<Alu> {
+        let mut vm = Vm::new(self, inputs);
+        writeln!(out, "{:>4}  {:<12} {}", "", "", vm.alu)?;
+        loop {
+            let pc = vm.pc();
+            match vm.step()? {
+                Some(instruction) => writeln!(
+                    out,
+                    "{:>4}  {:<12} {}",
+                    pc + 1,
+                    instruction.to_string(),
+                    vm.alu
+                )?,
+                None => return Ok(vm.alu),
+            }
+        }
+    }
+}
+
+impl FromStr for Program {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.as_bytes()).context("Failed to load the program")
+    }
+}
+
+/// The registers of the ALU.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Alu {
+    registers: [i64; 4],
+}
+
+impl Alu {
+    pub fn get(&self, register: Register) -> i64 {
+        self.registers[register.index()]
+    }
+
+    pub fn set(&mut self, register: Register, value: i64) {
+        self.registers[register.index()] = value;
+    }
+
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(register) => self.get(register),
+            Operand::Value(value) => value,
+        }
+    }
+
+    /// Apply one instruction, taking its input, if it needs any, from
+    /// `input`. Errors leave the registers unchanged.
+    fn execute(
+        &mut self,
+        instruction: &Instruction,
+        input: Option<i64>,
+    ) -> Result<(), ExecErrorKind> {
+        use Instruction::*;
+        let (a, result) = match *instruction {
+            Inp(a) => (a, input.ok_or(ExecErrorKind::OutOfInput)?),
+            Add(a, b) => (a, self.get(a).wrapping_add(self.value(b))),
+            Mul(a, b) => (a, self.get(a).wrapping_mul(self.value(b))),
+            Div(a, b) => {
+                let b = self.value(b);
+                if b == 0 {
+                    return Err(ExecErrorKind::DivideByZero);
+                }
+                (a, self.get(a).wrapping_div(b))
+            }
+            Mod(a, b) => {
+                let (a_value, b) = (self.get(a), self.value(b));
+                if a_value < 0 || b <= 0 {
+                    return Err(ExecErrorKind::InvalidModulo(a_value, b));
+                }
+                (a, a_value % b)
+            }
+            Eql(a, b) => (a, (self.get(a) == self.value(b)) as i64),
+        };
+        self.set(a, result);
+        Ok(())
+    }
+}
+
+impl fmt::Display for Alu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, register) in Register::ALL.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}={}", register, self.get(*register))?;
+        }
+        Ok(())
+    }
+}
+
+/// A program being run one instruction at a time.
+#[derive(Debug)]
+pub struct Vm<'a> {
+    program: &'a Program,
+    inputs: std::slice::Iter<'a, i64>,
+    alu: Alu,
+    pc: usize,
+}
+
+impl<'a> Vm<'a> {
+    pub fn new(program: &'a Program, inputs: &'a [i64]) -> Self {
+        Self {
+            program,
+            inputs: inputs.iter(),
+            alu: Alu::default(),
+            pc: 0,
+        }
+    }
+
+    /// The (0-based) index of the next instruction.
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn alu(&self) -> &Alu {
+        &self.alu
+    }
+
+    /// Execute the next instruction and return it, or `None` if the program
+    /// has finished.
+    pub fn step(&mut self) -> Result<Option<&'a Instruction>, ExecError> {
+        let instruction = match self.program.instructions.get(self.pc) {
+            Some(instruction) => instruction,
+            None => return Ok(None),
+        };
+        let input = match instruction {
+            Instruction::Inp(_) => self.inputs.next().copied(),
+            _ => None,
+        };
+        self.alu
+            .execute(instruction, input)
+            .map_err(|kind| ExecError { pc: self.pc, kind })?;
+        trace!(
+            "{:>4}  {:<12} {}",
+            self.pc + 1,
+            instruction.to_string(),
+            self.alu
+        );
+        self.pc += 1;
+        Ok(Some(instruction))
+    }
+}
+
+/// An instruction which could not be executed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecError {
+    /// The (0-based) index of the instruction.
+    pub pc: usize,
+    pub kind: ExecErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecErrorKind {
+    OutOfInput,
+    DivideByZero,
+    /// `mod a b` needs `a >= 0` and `b > 0`.
+    InvalidModulo(i64, i64),
+}
+
+impl fmt::Display for ExecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.pc + 1)?;
+        match self.kind {
+            ExecErrorKind::OutOfInput => write!(f, "there is no more input to read"),
+            ExecErrorKind::DivideByZero => write!(f, "division by zero"),
+            ExecErrorKind::InvalidModulo(a, b) => write!(f, "{} mod {} is undefined", a, b),
+        }
+    }
+}
+
+impl Error for ExecError {}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Register::W => "w",
+            Register::X => "x",
+            Register::Y => "y",
+            Register::Z => "z",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Register(register) => write!(f, "{}", register),
+            Operand::Value(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Instruction::*;
+        let (op, a, b) = match *self {
+            Inp(a) => return write!(f, "inp {}", a),
+            Add(a, b) => ("add", a, b),
+            Mul(a, b) => ("mul", a, b),
+            Div(a, b) => ("div", a, b),
+            Mod(a, b) => ("mod", a, b),
+            Eql(a, b) => ("eql", a, b),
+        };
+        write!(f, "{} {} {}", op, a, b)
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Instruction::*;
+
+        let mut tokens = s.split_whitespace();
+        let op = tokens
+            .next()
+            .ok_or_else(|| ParseError::end_of_line(s, "an instruction"))?;
+        let mut next = |expected| {
+            tokens
+                .next()
+                .ok_or_else(|| ParseError::end_of_line(s, expected))
+        };
+        let mut register = || {
+            let token = next("a register")?;
+            Register::from_str(token).map_err(|e| e.within(s, token))
+        };
+        let binary: Option<fn(Register, Operand) -> Instruction> = match op {
+            "inp" => None,
+            "add" => Some(Add),
+            "mul" => Some(Mul),
+            "div" => Some(Div),
+            "mod" => Some(Mod),
+            "eql" => Some(Eql),
+            _ => return Err(ParseError::at(s, op, "inp, add, mul, div, mod or eql")),
+        };
+        let target = register()?;
+        let instruction = match binary {
+            None => Inp(target),
+            Some(binary) => {
+                let token = next("an operand")?;
+                binary(
+                    target,
+                    Operand::from_str(token).map_err(|e| e.within(s, token))?,
+                )
+            }
+        };
+        if let Some(extra) = tokens.next() {
+            return Err(ParseError::at(s, extra, "end of line"));
+        }
+
+        Ok(instruction)
+    }
+}
+
+impl FromStr for Register {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "w" => Ok(Register::W),
+            "x" => Ok(Register::X),
+            "y" => Ok(Register::Y),
+            "z" => Ok(Register::Z),
+            _ => Err(ParseError::at(s, s, "a register")),
+        }
+    }
+}
+
+impl FromStr for Operand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Register::from_str(s) {
+            Ok(register) => Ok(Operand::Register(register)),
+            Err(_) => Ok(Operand::Value(parse_at(s, s, "a register or a number")?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BINARY: &str = "\
+inp w
+add z w
+mod z 2
+div w 2
+add y w
+mod y 2
+div w 2
+add x w
+mod x 2
+div w 2
+mod w 2";
+
+    #[test]
+    fn run() {
+        let program = Program::from_str(BINARY).unwrap();
+        let alu = program.run(&[13]).unwrap();
+        assert_eq!(alu.to_string(), "w=1 x=1 y=0 z=1");
+    }
+
+    #[test]
+    fn negate() {
+        let program = Program::from_str("inp x\nmul x -1").unwrap();
+        assert_eq!(program.run(&[7]).unwrap().get(Register::X), -7);
+    }
+
+    #[test]
+    fn trace() {
+        let program = Program::from_str("inp x\ninp y\nmul z 3\nadd z x\neql z y").unwrap();
+        let mut out = Vec::new();
+        program.trace(&[3, 9], &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "                   w=0 x=0 y=0 z=0
+   1  inp x        w=0 x=3 y=0 z=0
+   2  inp y        w=0 x=3 y=9 z=0
+   3  mul z 3      w=0 x=3 y=9 z=0
+   4  add z x      w=0 x=3 y=9 z=3
+   5  eql z y      w=0 x=3 y=9 z=0
+"
+        );
+    }
+
+    #[test]
+    fn step() {
+        let program = Program::from_str("inp w\nadd x w").unwrap();
+        let mut vm = Vm::new(&program, &[5]);
+        assert_eq!(vm.step().unwrap(), Some(&Instruction::Inp(Register::W)));
+        assert_eq!(vm.pc(), 1);
+        assert_eq!(vm.alu().get(Register::X), 0);
+        vm.step().unwrap();
+        assert_eq!(vm.alu().get(Register::X), 5);
+        assert_eq!(vm.step().unwrap(), None);
+    }
+
+    #[test]
+    fn exec_errors() {
+        let program = Program::from_str("inp w\ninp x\ndiv w x").unwrap();
+        assert_eq!(
+            program.run(&[1]).unwrap_err().to_string(),
+            "line 2: there is no more input to read"
+        );
+        assert_eq!(
+            program.run(&[1, 0]).unwrap_err(),
+            ExecError {
+                pc: 2,
+                kind: ExecErrorKind::DivideByZero
+            }
+        );
+        let program = Program::from_str("inp w\nmod w 26").unwrap();
+        assert_eq!(
+            program.run(&[-1]).unwrap_err().to_string(),
+            "line 2: -1 mod 26 is undefined"
+        );
+    }
+
+    #[test]
+    fn load_errors() {
+        let error = |source| format!("{:#}", Program::from_str(source).unwrap_err());
+        assert_eq!(
+            error("inp w\nadd 3 x"),
+            "Failed to load the program: line 2, column 5: expected a register, found \"3\""
+        );
+        assert_eq!(
+            error("inp w\nmul x\n"),
+            "Failed to load the program: line 2, column 6: expected an operand, found end of line"
+        );
+        assert_eq!(
+            error("sub x 1"),
+            "Failed to load the program: line 1, column 1: expected inp, add, mul, div, mod or eql, found \"sub\""
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use alu::Program;
+use anyhow::{bail, Context, Result};
+use log::debug;
+use std::io::{BufRead, Write};
+use utils::Solver;
+
+pub mod alu;
+pub mod monad;
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = u64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        Ok(Solution::new(Program::parse(reader)?))
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        self.model_number(false)
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        self.model_number(true)
+    }
+}
+
+#[derive(Debug)]
+pub struct Solution {
+    program: Program,
+}
+
+impl Solution {
+    fn new(program: Program) -> Self {
+        Self { program }
+    }
+
+    /// The program, block by block, with what each block does to `z`.
+    pub fn disassemble(&self) -> Result<String> {
+        monad::disassemble(&self.program)
+    }
+
+    /// Run the program on the digits of `number`, writing each instruction
+    /// and the registers after it to `out`.
+    pub fn trace<W: Write>(&self, number: u64, out: W) -> Result<()> {
+        let digits = monad::to_digits(number)
+            .with_context(|| format!("{} is not a model number", number))?;
+        self.program.trace(&digits, out)?;
+        Ok(())
+    }
+
+    /// Find the largest model number accepted by MONAD, or the smallest.
+    fn model_number(&self, smallest: bool) -> Result<u64> {
+        let analysis = monad::analyse(&self.program)?;
+        for constraint in &analysis.constraints {
+            debug!("constraint: {}", constraint);
+        }
+        let number = if smallest {
+            analysis.smallest()
+        } else {
+            analysis.largest()
+        };
+
+        // Symbolic execution made assumptions, so check the answer for real.
+        if let Err(rejection) = analysis.check(&self.program, number) {
+            bail!("MONAD rejects {}: {}", number, rejection);
+        }
+        Ok(number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::monad::tests::monad;
+    use crate::Solution;
+    use utils::Solver;
+
+    #[ctor::ctor]
+    fn init() {
+        env_logger::init();
+    }
+
+    #[test]
+    fn model_numbers() {
+        let mut solution = Solution::new(monad());
+        assert_eq!(solution.part1().unwrap(), 92915979999498);
+        assert_eq!(solution.part2().unwrap(), 21611513911181);
+    }
+
+    #[test]
+    fn listings() {
+        let mut solution = Solution::new(monad());
+        let listing = solution.disassemble().unwrap();
+        assert!(listing.starts_with("; digit 1: "));
+        assert_eq!(listing.matches("; digit").count(), 14);
+
+        let mut trace = Vec::new();
+        let number = solution.part1().unwrap();
+        solution.trace(number, &mut trace).unwrap();
+        let trace = String::from_utf8(trace).unwrap();
+        assert_eq!(trace.lines().count(), 252 + 1);
+        assert!(trace.trim_end().ends_with(" z=0"));
+        assert_eq!(
+            solution.trace(123, Vec::new()).unwrap_err().to_string(),
+            "123 is not a model number"
+        );
+    }
+
+    #[test]
+    fn load_error() {
+        let error = Solution::parse_str("inp w\nadd x\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: expected an operand, found end of line"
+        );
+    }
+}
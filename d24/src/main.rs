@@ -0,0 +1,34 @@
+use anyhow::Result;
+use d24::Solution;
+use log::info;
+use std::env;
+use std::io;
+use utils::Solver;
+use yansi::Paint;
+
+fn main() -> Result<()> {
+    env_logger::init();
+
+    let (part, mut solution) = Solution::from_args(24)?;
+    info!(
+        "{}{}: {:?}",
+        Paint::masked("🎄 "),
+        Paint::bold(Paint::yellow("solution")),
+        solution
+    );
+    let answer = solution.solve(part)?;
+    info!(
+        "{}answer is {}",
+        Paint::masked("🎅 "),
+        Paint::bold(Paint::red(answer))
+    );
+
+    if env::var_os("D24_DISASSEMBLE").is_some() {
+        print!("{}", solution.disassemble()?);
+    }
+    if env::var_os("D24_TRACE").is_some() {
+        solution.trace(answer, io::stdout().lock())?;
+    }
+
+    Ok(())
+}
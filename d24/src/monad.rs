@@ -0,0 +1,758 @@
+//! MONAD, the MOdel Number Automatic Detector.
+//!
+//! MONAD reads the 14 digits of a model number in turn, running a block of
+//! code for each, and accepts the number if `z` ends up as zero. Each block
+//! treats `z` as a stack of base 26 values: it either pushes its digit plus a
+//! constant, or pops the value pushed by an earlier block, and pushes again
+//! unless its own digit equals the popped value plus another constant.
+//! With as many pops as pushes, `z` can only end up empty if every pop finds
+//! its digit matching, which ties each popping digit to an earlier one.
+//!
+//! Rather than rely on the shape of the code, [`analyse`] executes the
+//! program symbolically, tracking each register as a constant, a digit plus
+//! a constant, or a stack of those, and assuming that every comparison which
+//! could go either way between two digits finds them equal.
+use crate::alu::{ExecError, Instruction, Operand, Program, Register, Vm};
+use anyhow::{bail, ensure, Context, Result};
+use std::error::Error;
+use std::fmt;
+
+/// The number of digits in a model number.
+pub const DIGITS: usize = 14;
+
+/// Model number digits run from 1 to 9.
+const MIN_DIGIT: i64 = 1;
+const MAX_DIGIT: i64 = 9;
+
+/// The part of a program which handles one digit: from its `inp` up to the
+/// next one.
+#[derive(Debug, Clone, Copy)]
+pub struct Block<'a> {
+    /// The (0-based) digit the block reads.
+    pub digit: usize,
+    /// The (0-based) index of the block's first instruction.
+    pub start: usize,
+    pub instructions: &'a [Instruction],
+}
+
+/// Split `program` into its 14 per-digit blocks.
+pub fn blocks(program: &Program) -> Result<Vec<Block<'_>>> {
+    let instructions = program.instructions();
+    ensure!(
+        matches!(instructions.first(), Some(Instruction::Inp(_))),
+        "MONAD should start by reading a digit"
+    );
+    let starts = instructions
+        .iter()
+        .enumerate()
+        .filter(|(_, instruction)| matches!(instruction, Instruction::Inp(_)))
+        .map(|(start, _)| start)
+        .collect::<Vec<_>>();
+    ensure!(
+        starts.len() == DIGITS,
+        "MONAD should read {} digits, not {}",
+        DIGITS,
+        starts.len()
+    );
+    Ok(starts
+        .iter()
+        .enumerate()
+        .map(|(digit, &start)| {
+            let end = starts.get(digit + 1).copied().unwrap_or(instructions.len());
+            Block {
+                digit,
+                start,
+                instructions: &instructions[start..end],
+            }
+        })
+        .collect())
+}
+
+/// List `program` block by block, each headed by what it does to `z`, if
+/// [`analyse`] can work that out.
+pub fn disassemble(program: &Program) -> Result<String> {
+    let blocks = blocks(program)?;
+    let analysis = analyse(program).ok();
+    let mut listing = String::new();
+    for block in blocks {
+        let effect = analysis
+            .as_ref()
+            .map(|analysis| format!(": {}", analysis.effects[block.digit]))
+            .unwrap_or_default();
+        listing.push_str(&format!("; digit {}{}\n", block.digit + 1, effect));
+        for (offset, instruction) in block.instructions.iter().enumerate() {
+            listing.push_str(&format!(
+                "{:>4}  {}\n",
+                block.start + offset + 1,
+                instruction
+            ));
+        }
+    }
+    Ok(listing)
+}
+
+/// A digit plus a constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Term {
+    /// The (0-based) digit.
+    pub digit: usize,
+    pub offset: i64,
+}
+
+impl Term {
+    fn min(&self) -> i64 {
+        MIN_DIGIT + self.offset
+    }
+
+    fn max(&self) -> i64 {
+        MAX_DIGIT + self.offset
+    }
+
+    /// Whether the term always lies within `0..modulus`, and so fits in one
+    /// place of `z`.
+    fn fits(&self, modulus: i64) -> bool {
+        self.min() >= 0 && self.max() < modulus
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "d{}", self.digit + 1)?;
+        match self.offset {
+            0 => Ok(()),
+            offset if offset < 0 => write!(f, " - {}", -offset),
+            offset => write!(f, " + {}", offset),
+        }
+    }
+}
+
+/// Digit `later` must equal digit `earlier` plus `offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Constraint {
+    pub earlier: usize,
+    pub later: usize,
+    pub offset: i64,
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let term = Term {
+            digit: self.earlier,
+            offset: self.offset,
+        };
+        write!(f, "d{} = {}", self.later + 1, term)
+    }
+}
+
+/// What a block does to the stack in `z`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Effect {
+    Push(Term),
+    Pop(Constraint),
+}
+
+impl fmt::Display for Effect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Effect::Push(term) => write!(f, "push {}", term),
+            Effect::Pop(constraint) => write!(f, "pop, if {}", constraint),
+        }
+    }
+}
+
+/// What symbolic execution found out about a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    /// What each block does to `z`.
+    pub effects: Vec<Effect>,
+    /// Every digit appears in at most one of these.
+    pub constraints: Vec<Constraint>,
+}
+
+impl Analysis {
+    /// How each digit is chosen: freely between two bounds, or fixed by an
+    /// earlier digit.
+    fn choices(&self) -> [Choice; DIGITS] {
+        let mut choices = [Choice::Free(MIN_DIGIT, MAX_DIGIT); DIGITS];
+        for constraint in &self.constraints {
+            choices[constraint.earlier] = Choice::Free(
+                MIN_DIGIT.max(MIN_DIGIT - constraint.offset),
+                MAX_DIGIT.min(MAX_DIGIT - constraint.offset),
+            );
+            choices[constraint.later] = Choice::Fixed(constraint.earlier, constraint.offset);
+        }
+        choices
+    }
+
+    /// The largest model number meeting every constraint.
+    pub fn largest(&self) -> u64 {
+        to_number(&digits_from(&self.choices(), |_, _, high| high))
+    }
+
+    /// The smallest model number meeting every constraint.
+    pub fn smallest(&self) -> u64 {
+        to_number(&digits_from(&self.choices(), |_, low, _| low))
+    }
+
+    /// Every model number meeting every constraint, smallest first.
+    pub fn model_numbers(&self) -> ModelNumbers {
+        let choices = self.choices();
+        let next = if choices
+            .iter()
+            .all(|choice| !matches!(choice, Choice::Free(low, high) if low > high))
+        {
+            Some(digits_from(&choices, |_, low, _| low))
+        } else {
+            None
+        };
+        ModelNumbers { choices, next }
+    }
+
+    /// How many model numbers meet every constraint.
+    pub fn count(&self) -> u64 {
+        self.choices()
+            .iter()
+            .map(|choice| match choice {
+                Choice::Free(low, high) => (high - low + 1).max(0) as u64,
+                Choice::Fixed(..) => 1,
+            })
+            .product()
+    }
+
+    /// Run `number` through `program`, block by block, and explain why it is
+    /// rejected, if it is.
+    pub fn check(&self, program: &Program, number: u64) -> Result<(), Rejection> {
+        let digits = to_digits(number).ok_or(Rejection::Malformed(number))?;
+        let blocks = blocks(program).map_err(|_| Rejection::Malformed(number))?;
+        let mut vm = Vm::new(program, &digits);
+        for (block, effect) in blocks.iter().zip(&self.effects) {
+            let before = vm.alu().get(Register::Z);
+            while vm.pc() < block.start + block.instructions.len() {
+                vm.step().map_err(Rejection::Failed)?;
+            }
+            let after = vm.alu().get(Register::Z);
+            if let Effect::Pop(constraint) = effect {
+                if after != before / BASE {
+                    return Err(Rejection::Block {
+                        start: block.start,
+                        end: block.start + block.instructions.len(),
+                        constraint: *constraint,
+                        earlier: digits[constraint.earlier],
+                        later: digits[constraint.later],
+                    });
+                }
+            }
+        }
+        match vm.alu().get(Register::Z) {
+            0 => Ok(()),
+            z => Err(Rejection::Unexplained(z)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Choice {
+    /// Any digit from the first to the second.
+    Free(i64, i64),
+    /// The given, earlier, digit plus a constant.
+    Fixed(usize, i64),
+}
+
+/// Choose each free digit with `pick`, given its position and bounds, and
+/// work out the others.
+fn digits_from(choices: &[Choice; DIGITS], pick: impl Fn(usize, i64, i64) -> i64) -> [i64; DIGITS] {
+    let mut digits = [0; DIGITS];
+    for (i, choice) in choices.iter().enumerate() {
+        digits[i] = match *choice {
+            Choice::Free(low, high) => pick(i, low, high),
+            Choice::Fixed(earlier, offset) => digits[earlier] + offset,
+        };
+    }
+    digits
+}
+
+/// An iterator over the model numbers which meet a set of constraints, in
+/// increasing order. Every constraint fixes a later digit from an earlier
+/// one, so counting up through the free digits alone counts up through the
+/// numbers.
+#[derive(Debug, Clone)]
+pub struct ModelNumbers {
+    choices: [Choice; DIGITS],
+    next: Option<[i64; DIGITS]>,
+}
+
+impl Iterator for ModelNumbers {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut digits = self.next?;
+        let number = to_number(&digits);
+
+        // Find the last free digit which can still go up, and reset those after it.
+        let mut carry = true;
+        for i in (0..DIGITS).rev() {
+            if let Choice::Free(low, high) = self.choices[i] {
+                if digits[i] < high {
+                    digits[i] += 1;
+                    carry = false;
+                    break;
+                }
+                digits[i] = low;
+            }
+        }
+        self.next = if carry {
+            None
+        } else {
+            Some(digits_from(&self.choices, |i, _, _| digits[i]))
+        };
+        Some(number)
+    }
+}
+
+/// Why MONAD rejects a model number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    /// The number does not have 14 digits, all from 1 to 9, or the program
+    /// does not have a block for each.
+    Malformed(u64),
+    /// The program failed while checking the number.
+    Failed(ExecError),
+    /// The block of instructions `start..end` (0-based) pushed a value where it
+    /// should have popped one, as the digits break its constraint.
+    Block {
+        start: usize,
+        end: usize,
+        constraint: Constraint,
+        earlier: i64,
+        later: i64,
+    },
+    /// Every block behaved, yet `z` did not end up as zero.
+    Unexplained(i64),
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::Malformed(number) => write!(
+                f,
+                "{} is not a model number: it needs {} digits, all from 1 to 9",
+                number, DIGITS
+            ),
+            Rejection::Failed(error) => write!(f, "MONAD failed at {}", error),
+            Rejection::Block {
+                start,
+                end,
+                constraint,
+                earlier,
+                later,
+            } => write!(
+                f,
+                "the block for digit {} (lines {}-{}) rejects it: it needs {}, but d{} is {} and d{} is {}",
+                constraint.later + 1,
+                start + 1,
+                end,
+                constraint,
+                constraint.earlier + 1,
+                earlier,
+                constraint.later + 1,
+                later
+            ),
+            Rejection::Unexplained(z) => write!(f, "MONAD leaves z = {}", z),
+        }
+    }
+}
+
+impl Error for Rejection {}
+
+/// The digits, most significant first, as a number.
+pub fn to_number(digits: &[i64]) -> u64 {
+    digits
+        .iter()
+        .fold(0, |number, digit| number * 10 + *digit as u64)
+}
+
+/// The digits of `number`, if it is a model number: 14 digits, none of them 0.
+pub fn to_digits(number: u64) -> Option<[i64; DIGITS]> {
+    let mut digits = [0; DIGITS];
+    let mut rest = number;
+    for digit in digits.iter_mut().rev() {
+        *digit = (rest % 10) as i64;
+        rest /= 10;
+    }
+    if rest != 0 || digits.contains(&0) {
+        return None;
+    }
+    Some(digits)
+}
+
+/// A register's value, as far as symbolic execution can tell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Constant(i64),
+    Term(Term),
+    /// A base 26 stack of terms, the last on top, holding at least two
+    /// terms: fewer are held as a [`Constant`] zero or a single [`Term`].
+    Stack(Vec<Term>),
+    /// A stack which has been shifted up, with a zero on top waiting to be
+    /// replaced by a term.
+    Shifted(Vec<Term>),
+}
+
+use Value::*;
+
+const BASE: i64 = 26;
+
+/// Execute `program` symbolically, working out what each block does and what
+/// the digits of an accepted model number must satisfy.
+pub fn analyse(program: &Program) -> Result<Analysis> {
+    let blocks = blocks(program)?;
+    let mut registers = [Constant(0), Constant(0), Constant(0), Constant(0)];
+    let mut effects = Vec::new();
+    let mut constraints = Vec::new();
+    for block in &blocks {
+        let depth = depth(&registers[Register::Z as usize]);
+        let mut constraint = None;
+        for (offset, instruction) in block.instructions.iter().enumerate() {
+            let line = block.start + offset + 1;
+            execute(&mut registers, block.digit, instruction, &mut constraint)
+                .with_context(|| format!("line {}: cannot follow {}", line, instruction))?;
+        }
+        let z = &registers[Register::Z as usize];
+        let stack = stack_of(z);
+        let effect = match (
+            stack.len() as isize - depth as isize,
+            stack.last(),
+            constraint,
+        ) {
+            (1, Some(top), None) => Effect::Push(*top),
+            (-1, _, Some(constraint)) => Effect::Pop(constraint),
+            _ => bail!(
+                "Digit {} should either push a term onto z or pop one off, leaving {}",
+                block.digit + 1,
+                describe(z)
+            ),
+        };
+        if let Effect::Pop(constraint) = effect {
+            constraints.push(constraint);
+        }
+        effects.push(effect);
+    }
+    let z = &registers[Register::Z as usize];
+    ensure!(
+        depth(z) == 0,
+        "MONAD leaves {} in z, so accepts no model number",
+        describe(z)
+    );
+    Ok(Analysis {
+        effects,
+        constraints,
+    })
+}
+
+/// Whether `value` can be treated as a base 26 stack.
+fn is_stack(value: &Value) -> bool {
+    match value {
+        Constant(0) | Stack(_) => true,
+        Term(term) => term.fits(BASE),
+        _ => false,
+    }
+}
+
+/// The terms in `value`, if it is a stack, from the bottom up.
+fn stack_of(value: &Value) -> Vec<Term> {
+    match value {
+        Term(term) if term.fits(BASE) => vec![*term],
+        Stack(stack) => stack.clone(),
+        _ => Vec::new(),
+    }
+}
+
+fn from_stack(mut stack: Vec<Term>) -> Value {
+    match stack.len() {
+        0 => Constant(0),
+        1 => Term(stack.remove(0)),
+        _ => Stack(stack),
+    }
+}
+
+fn depth(value: &Value) -> usize {
+    stack_of(value).len()
+}
+
+fn describe(value: &Value) -> String {
+    match value {
+        Constant(value) => value.to_string(),
+        Term(term) => term.to_string(),
+        Stack(stack) | Shifted(stack) => format!(
+            "[{}]",
+            stack
+                .iter()
+                .map(|term| term.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn execute(
+    registers: &mut [Value; 4],
+    digit: usize,
+    instruction: &Instruction,
+    constraint: &mut Option<Constraint>,
+) -> Result<()> {
+    use Instruction::*;
+    let (a, b) = match *instruction {
+        Inp(a) => {
+            registers[a as usize] = Term(Term { digit, offset: 0 });
+            return Ok(());
+        }
+        Add(a, b) | Mul(a, b) | Div(a, b) | Mod(a, b) | Eql(a, b) => (a, b),
+    };
+    let left = registers[a as usize].clone();
+    let right = match b {
+        Operand::Register(b) => registers[b as usize].clone(),
+        Operand::Value(value) => Constant(value),
+    };
+    let result = match (instruction, left, right) {
+        (Add(..), Constant(x), Constant(y)) => Constant(x + y),
+        (Add(..), value, Constant(0)) | (Add(..), Constant(0), value) => value,
+        (Add(..), Term(term), Constant(c)) | (Add(..), Constant(c), Term(term)) => Term(Term {
+            offset: term.offset + c,
+            ..term
+        }),
+        (Add(..), Shifted(mut stack), Term(term)) if term.fits(BASE) => {
+            stack.push(term);
+            from_stack(stack)
+        }
+
+        (Mul(..), Constant(x), Constant(y)) => Constant(x * y),
+        (Mul(..), _, Constant(0)) | (Mul(..), Constant(0), _) => Constant(0),
+        (Mul(..), value, Constant(1)) | (Mul(..), Constant(1), value) => value,
+        (Mul(..), value, Constant(BASE)) if is_stack(&value) => Shifted(stack_of(&value)),
+
+        (Div(..), _, Constant(0)) => bail!("division by zero"),
+        (Div(..), Constant(x), Constant(y)) => Constant(x / y),
+        (Div(..), value, Constant(1)) => value,
+        (Div(..), value, Constant(BASE)) if is_stack(&value) => {
+            let mut stack = stack_of(&value);
+            stack.pop();
+            from_stack(stack)
+        }
+
+        (Mod(..), Constant(x), Constant(y)) if x >= 0 && y > 0 => Constant(x % y),
+        (Mod(..), Term(term), Constant(m)) if term.fits(m) => Term(term),
+        (Mod(..), Stack(stack), Constant(BASE)) => Term(stack[stack.len() - 1]),
+
+        (Eql(..), Constant(x), Constant(y)) => Constant((x == y) as i64),
+        (Eql(..), Term(term), Constant(c)) | (Eql(..), Constant(c), Term(term))
+            if c < term.min() || c > term.max() =>
+        {
+            Constant(0)
+        }
+        (Eql(..), Term(x), Term(y)) if x == y => Constant(1),
+        (Eql(..), Term(x), Term(y)) if x.max() < y.min() || y.max() < x.min() => Constant(0),
+        (Eql(..), Term(x), Term(y)) if x.digit != y.digit => {
+            // Assume the digits match; otherwise the stack could not shrink.
+            ensure!(
+                constraint.is_none(),
+                "digit {} is already constrained",
+                digit + 1
+            );
+            let (earlier, later) = if x.digit < y.digit { (x, y) } else { (y, x) };
+            *constraint = Some(Constraint {
+                earlier: earlier.digit,
+                later: later.digit,
+                offset: earlier.offset - later.offset,
+            });
+            Constant(1)
+        }
+
+        (_, left, right) => bail!(
+            "{} with {} and {} is not supported",
+            instruction_name(instruction),
+            describe(&left),
+            describe(&right)
+        ),
+    };
+    registers[a as usize] = result;
+    Ok(())
+}
+
+fn instruction_name(instruction: &Instruction) -> &'static str {
+    use Instruction::*;
+    match instruction {
+        Inp(_) => "inp",
+        Add(..) => "add",
+        Mul(..) => "mul",
+        Div(..) => "div",
+        Mod(..) => "mod",
+        Eql(..) => "eql",
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    /// The parameters of each block: what `z` is divided by, and the
+    /// constants added to `x` and `y`.
+    const PARAMETERS: [(i64, i64, i64); DIGITS] = [
+        (1, 12, 4),
+        (1, 11, 11),
+        (1, 13, 5),
+        (1, 11, 11),
+        (1, 14, 14),
+        (26, -10, 7),
+        (1, 11, 11),
+        (26, -9, 4),
+        (26, -3, 6),
+        (1, 13, 5),
+        (26, -5, 9),
+        (26, -10, 12),
+        (26, -4, 14),
+        (26, -5, 14),
+    ];
+
+    /// A MONAD program in the usual shape.
+    pub(crate) fn monad() -> Program {
+        let source = PARAMETERS
+            .iter()
+            .map(|(div, check, offset)| {
+                format!(
+                    "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+                     mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\n\
+                     add y {}\nmul y x\nadd z y",
+                    div, check, offset
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        Program::from_str(&source).unwrap()
+    }
+
+    fn digits(number: u64) -> Vec<i64> {
+        number
+            .to_string()
+            .chars()
+            .map(|c| c.to_digit(10).unwrap() as i64)
+            .collect()
+    }
+
+    #[test]
+    fn split_into_blocks() {
+        let program = monad();
+        let blocks = blocks(&program).unwrap();
+        assert_eq!(blocks.len(), DIGITS);
+        assert!(blocks.iter().all(|block| block.instructions.len() == 18));
+        assert_eq!(blocks[3].start, 54);
+
+        let short = Program::from_str("inp w\nadd z w").unwrap();
+        assert_eq!(
+            super::blocks(&short).unwrap_err().to_string(),
+            "MONAD should read 14 digits, not 1"
+        );
+    }
+
+    #[test]
+    fn constraints() {
+        let analysis = analyse(&monad()).unwrap();
+        assert_eq!(
+            analysis
+                .constraints
+                .iter()
+                .map(|constraint| constraint.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "d6 = d5 + 4",
+                "d8 = d7 + 2",
+                "d9 = d4 + 8",
+                "d11 = d10",
+                "d12 = d3 - 5",
+                "d13 = d2 + 7",
+                "d14 = d1 - 1",
+            ]
+        );
+        assert_eq!(
+            analysis.effects[0],
+            Effect::Push(Term {
+                digit: 0,
+                offset: 4
+            })
+        );
+        assert_eq!(analysis.effects[5].to_string(), "pop, if d6 = d5 + 4");
+    }
+
+    #[test]
+    fn model_numbers() {
+        let program = monad();
+        let analysis = analyse(&program).unwrap();
+        assert_eq!(analysis.largest(), 92915979999498);
+        assert_eq!(analysis.smallest(), 21611513911181);
+        for number in [analysis.largest(), analysis.smallest()] {
+            let alu = program.run(&digits(number)).unwrap();
+            assert_eq!(alu.get(Register::Z), 0, "{} should be accepted", number);
+        }
+        let alu = program.run(&digits(analysis.largest() - 1)).unwrap();
+        assert_ne!(alu.get(Register::Z), 0);
+    }
+
+    #[test]
+    fn every_model_number() {
+        let program = monad();
+        let analysis = analyse(&program).unwrap();
+        let numbers = analysis.model_numbers().collect::<Vec<_>>();
+        assert_eq!(analysis.count(), 20160);
+        assert_eq!(numbers.len() as u64, analysis.count());
+        assert_eq!(numbers.first(), Some(&analysis.smallest()));
+        assert_eq!(numbers.last(), Some(&analysis.largest()));
+        assert!(numbers.windows(2).all(|pair| pair[0] < pair[1]));
+        for number in numbers.iter().step_by(997) {
+            assert_eq!(analysis.check(&program, *number), Ok(()));
+        }
+    }
+
+    #[test]
+    fn rejections() {
+        let program = monad();
+        let analysis = analyse(&program).unwrap();
+        assert_eq!(
+            analysis
+                .check(&program, 21611313911181)
+                .unwrap_err()
+                .to_string(),
+            "the block for digit 6 (lines 91-108) rejects it: \
+             it needs d6 = d5 + 4, but d5 is 1 and d6 is 3"
+        );
+        assert_eq!(
+            analysis.check(&program, 21611513911180),
+            Err(Rejection::Malformed(21611513911180))
+        );
+        assert_eq!(
+            analysis.check(&program, 2161151391118),
+            Err(Rejection::Malformed(2161151391118))
+        );
+    }
+
+    #[test]
+    fn disassembly() {
+        let listing = disassemble(&monad()).unwrap();
+        let lines = listing.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), DIGITS * 19);
+        assert_eq!(lines[0], "; digit 1: push d1 + 4");
+        assert_eq!(lines[1], "   1  inp w");
+        assert_eq!(lines[5 * 19], "; digit 6: pop, if d6 = d5 + 4");
+        assert_eq!(lines[5 * 19 + 5], "  95  div z 26");
+    }
+
+    #[test]
+    fn unsupported() {
+        let mut source = "inp w\nmul w w\n".to_string();
+        source.push_str(&"inp w\n".repeat(DIGITS - 1));
+        let program = Program::from_str(&source).unwrap();
+        assert_eq!(
+            format!("{:#}", analyse(&program).unwrap_err()),
+            "line 2: cannot follow mul w w: mul with d1 and d1 is not supported"
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use d25::Solution;
+
+fn bench(c: &mut Criterion) {
+    utils::bench::solver::<Solution>(c, 25, 1);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
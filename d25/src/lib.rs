@@ -0,0 +1,133 @@
+use anyhow::{bail, Result};
+use log::debug;
+use std::fmt;
+use std::io::BufRead;
+use utils::{Grid, Solver};
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
+
+    fn parse<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        let world = Grid::parse_with(&input, "a sea cucumber", |c| match c {
+            '>' => Some(Cucumber::East),
+            'v' => Some(Cucumber::South),
+            '.' => Some(Cucumber::None),
+            _ => None,
+        })?;
+
+        Ok(Solution::new(world))
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        Ok(self.settle())
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        bail!("There is no puzzle for day 25 part 2")
+    }
+}
+
+#[derive(Debug)]
+pub struct Solution {
+    world: Grid<Cucumber>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cucumber {
+    None,
+    East,
+    South,
+}
+
+impl fmt::Display for Cucumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cucumber::East => '>',
+            Cucumber::South => 'v',
+            Cucumber::None => '.',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+impl Solution {
+    fn new(world: Grid<Cucumber>) -> Self {
+        Self { world }
+    }
+
+    /// Move the herds until none of them can, returning the number of passes taken.
+    fn settle(&mut self) -> i64 {
+        self.dump();
+        let mut num_passes = 0;
+        loop {
+            num_passes += 1;
+            let last_changes = self.pass();
+            debug!("last_changes: {}", last_changes);
+            if last_changes == 0 {
+                break;
+            }
+            self.dump();
+        }
+        num_passes
+    }
+}
+
+impl Solution {
+    fn dump(&self) {
+        debug!("\n{}", self.world);
+    }
+
+    fn pass(&mut self) -> i32 {
+        let mut num_changes = 0;
+        num_changes += self.sub_pass(Cucumber::East, 1, 0);
+        num_changes += self.sub_pass(Cucumber::South, 0, 1);
+        num_changes
+    }
+
+    /// Move every cucumber of the given kind one step, if there is space.
+    fn sub_pass(&mut self, kind: Cucumber, dx: isize, dy: isize) -> i32 {
+        // All cucumbers of a herd look before any of them moves
+        let moves = self
+            .world
+            .iter()
+            .filter(|(_, cucumber)| **cucumber == kind)
+            .map(|((x, y), _)| (x as isize, y as isize))
+            .filter_map(|(x, y)| Some(((x, y), self.world.wrap(x + dx, y + dy)?)))
+            .filter(|(_, (x, y))| self.world.get(*x, *y) == Some(&Cucumber::None))
+            .collect::<Vec<_>>();
+        for ((x, y), (next_x, next_y)) in &moves {
+            self.world.set(*x, *y, Cucumber::None);
+            self.world.set(*next_x, *next_y, kind);
+        }
+        moves.len() as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = "\
+v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>
+";
+
+    #[test]
+    fn example() {
+        let mut solution = Solution::parse_str(EXAMPLE).unwrap();
+        assert_eq!(solution.part1().unwrap(), 58);
+    }
+}
@@ -1,26 +1,26 @@
 use anyhow::Result;
-use d25p1::load;
+use d25::Solution;
 use log::{error, info};
+use utils::Solver;
 use yansi::Paint;
 
 fn main() -> Result<()> {
     env_logger::init();
 
-    let mut solution = load("input.d25p1.full")?;
+    let (part, mut solution) = Solution::from_args(25)?;
     info!(
         "{}{}: {:?}",
         Paint::masked("🎄 "),
         Paint::bold(Paint::yellow("solution")),
         solution
     );
-    solution.analyse();
-    match solution.answer() {
-        Some(answer) => info!(
+    match solution.solve(part) {
+        Ok(answer) => info!(
             "{}answer is {:?}",
             Paint::masked("🎅 "),
             Paint::bold(Paint::red(answer))
         ),
-        _ => error!("{}No answer to the problem", Paint::masked("🎅 ")),
+        Err(e) => error!("{}{}", Paint::masked("🎅 "), e),
     }
 
     Ok(())
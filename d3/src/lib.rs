@@ -0,0 +1,221 @@
+use anyhow::{Context, Result};
+use log::debug;
+use std::collections::HashMap;
+use std::io::BufRead;
+use utils::{ParseError, Solver};
+
+/// The widest reading the rates can be worked out for.
+const MAX_BITS: usize = 31;
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut solution = Solution::new();
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let reading = line.trim();
+            if let Some((column, c)) = reading
+                .char_indices()
+                .find(|(_, c)| !matches!(c, '0' | '1'))
+            {
+                return Err(ParseError::at(
+                    &line,
+                    &reading[column..column + c.len_utf8()],
+                    "a bit",
+                )
+                .with_line(number + 1)
+                .into());
+            }
+            let width = solution.data.first().map(|first| first.len());
+            check_width(&line, reading, width).map_err(|e| e.with_line(number + 1))?;
+            solution.add(reading.to_string());
+        }
+        if solution.data.is_empty() {
+            return Err(ParseError::end_of_input("a reading").with_line(1).into());
+        }
+        Ok(solution)
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        let (count, freq) = self
+            .data
+            .iter()
+            .map(|v| v.as_str())
+            .fold((0, HashMap::new()), update_bit_counts);
+
+        let (gamma, epsilon) = calculate_rates(count, &freq);
+        debug!("total = {} x {} = {}", epsilon, gamma, epsilon * gamma);
+        Ok((epsilon * gamma) as i64)
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        let oxygen = calculate_rating(&self.data, |count_ones, total| {
+            count_ones >= (total - count_ones)
+        })?;
+        debug!("oxygen = {}", oxygen);
+        let co2 = calculate_rating(&self.data, |count_ones, total| {
+            count_ones < (total - count_ones)
+        })?;
+        debug!("co2 = {}", co2);
+        debug!("{} x {} = {}", oxygen, co2, oxygen * co2);
+        Ok(oxygen * co2)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Solution {
+    data: Vec<String>,
+}
+
+impl Solution {
+    fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    fn add(&mut self, reading: String) {
+        self.data.push(reading);
+    }
+}
+
+/// Check that `reading`, a slice of `line`, is as wide as the first reading,
+/// or for the first, that it is neither empty nor too wide.
+fn check_width(line: &str, reading: &str, width: Option<usize>) -> Result<(), ParseError> {
+    let (limit, too_wide) = match width {
+        Some(width) => (width, "end of line".to_string()),
+        None => (MAX_BITS, format!("at most {} bits", MAX_BITS)),
+    };
+    if reading.len() > limit {
+        return Err(ParseError::at(line, &reading[limit..limit + 1], too_wide));
+    }
+    match width {
+        None if reading.is_empty() => Err(ParseError::end_of_line(line, "a bit")),
+        Some(width) if reading.len() < width => {
+            Err(ParseError::end_of_line(line, format!("{} bits", width)))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn calculate_rates(count: i32, freq: &HashMap<usize, i32>) -> (i32, i32) {
+    let mut epsilon = 0;
+    let mut gamma = 0;
+    for (k, reading) in freq {
+        debug!("{} {} / {}", k, reading, count);
+        let increment = 1 << k;
+        if *reading > (count >> 1) {
+            epsilon += increment;
+        } else {
+            gamma += increment;
+        }
+    }
+    (gamma, epsilon)
+}
+
+fn update_bit_counts(
+    (count, mut acc): (i32, HashMap<usize, i32>),
+    v: &str,
+) -> (i32, HashMap<usize, i32>) {
+    for (index, value) in v.chars().rev().enumerate() {
+        if value == '1' {
+            *acc.entry(index).or_insert(0) += 1;
+        }
+    }
+    (count + 1, acc)
+}
+
+/// The reading left after keeping, bit by bit from the left, only those with
+/// a 1 there if `rule` says so given how many of them have a 1 and how many
+/// there are, or with a 0 otherwise. Filtering stops once one reading is left,
+/// and skips bits which all the readings left share.
+fn calculate_rating(data: &[String], rule: impl Fn(usize, usize) -> bool) -> Result<i64> {
+    let mut readings = data.iter().map(|v| v.as_bytes()).collect::<Vec<_>>();
+    let width = readings.first().map_or(0, |v| v.len());
+    for position in 0..width {
+        if readings.len() <= 1 {
+            break;
+        }
+        let count_ones = readings.iter().filter(|v| v[position] == b'1').count();
+        if count_ones == 0 || count_ones == readings.len() {
+            continue;
+        }
+        let bit = if rule(count_ones, readings.len()) {
+            b'1'
+        } else {
+            b'0'
+        };
+        readings.retain(|v| v[position] == bit);
+        debug!("bit {}: {} readings left", position, readings.len());
+    }
+    // Any readings still left over are all the same.
+    let reading = readings.first().context("There are no readings")?;
+    let reading = std::str::from_utf8(reading)?;
+    debug!("{}", reading);
+    Ok(i64::from_str_radix(reading, 2)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = "\
+00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010
+";
+
+    #[test]
+    fn example() {
+        let mut solution = Solution::parse_str(EXAMPLE).unwrap();
+        assert_eq!(solution.part1().unwrap(), 198);
+        assert_eq!(solution.part2().unwrap(), 230);
+    }
+
+    #[test]
+    fn few_readings() {
+        let part2 = |input| Solution::parse_str(input).unwrap().part2().unwrap();
+        assert_eq!(part2("1\n"), 1);
+        assert_eq!(part2("00\n00\n"), 0);
+        assert_eq!(part2("10\n10\n11\n"), 2 * 3);
+    }
+
+    #[test]
+    fn malformed_readings() {
+        let error = |input| Solution::parse_str(input).unwrap_err().to_string();
+        assert_eq!(
+            error(""),
+            "line 1, column 1: expected a reading, found end of input"
+        );
+        assert_eq!(
+            error("01\n011\n"),
+            "line 2, column 3: expected end of line, found \"1\""
+        );
+        assert_eq!(
+            error("011\n01\n"),
+            "line 2, column 3: expected 3 bits, found end of line"
+        );
+        assert_eq!(
+            error("01\n\n01\n"),
+            "line 2, column 1: expected 2 bits, found end of line"
+        );
+        assert_eq!(
+            error(&"1".repeat(32)),
+            "line 1, column 32: expected at most 31 bits, found \"1\""
+        );
+    }
+}
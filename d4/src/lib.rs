@@ -0,0 +1,232 @@
+use anyhow::{Context, Result};
+use log::debug;
+use std::io::BufRead;
+use utils::{parse_at, ParseError, Solver};
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
+
+    fn parse<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut draws = String::new();
+
+        reader.read_line(&mut draws)?;
+        let mut solution = Solution::new(parse_numbers(&draws, ',').map_err(|e| e.with_line(1))?);
+        let mut current_grid = Grid::new();
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = parse_numbers(&line, ' ').map_err(|e| e.with_line(number + 2))?;
+            debug!("line: {:?}", line);
+            match line.len() {
+                0 => {
+                    debug!("New grid");
+                    if current_grid.len() > 0 {
+                        let closing_grid = current_grid;
+                        current_grid = Grid::new();
+                        solution.add(closing_grid);
+                    }
+                }
+                _ => {
+                    current_grid.add_row(&line);
+                }
+            }
+        }
+        if current_grid.len() > 0 {
+            solution.add(current_grid);
+        }
+        Ok(solution)
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        let first_complete = self
+            .play()
+            .into_iter()
+            .filter(|grid| grid.complete_index() != -1)
+            .min_by_key(|grid| grid.complete_index())
+            .context("No grid is ever completed")?;
+        Ok(first_complete.score())
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        let last_complete = self
+            .play()
+            .into_iter()
+            .max_by_key(|grid| grid.complete_index())
+            .context("There are no grids")?;
+        Ok(last_complete.score())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Solution {
+    draws: Vec<i64>,
+    grids: Vec<Grid>,
+}
+
+impl Solution {
+    fn new(draws: Vec<i64>) -> Self {
+        Self {
+            draws,
+            ..Default::default()
+        }
+    }
+
+    fn add(&mut self, grid: Grid) {
+        self.grids.push(grid);
+    }
+
+    /// Every grid, marked with the draws, recording when and on which draw
+    /// it was completed.
+    fn play(&self) -> Vec<Grid> {
+        debug!("{:?} {:?}", self.draws, self.grids);
+
+        let mut grids = self
+            .grids
+            .iter()
+            .map(|grid| grid.clone(&|cell, _complete_idx| cell))
+            .collect::<Vec<_>>();
+        for (idx, draw) in self.draws.iter().enumerate() {
+            let mut new_grids = Vec::new();
+            for grid in grids {
+                let new_grid = grid.mark(*draw);
+                new_grids.push(new_grid);
+            }
+            grids = new_grids;
+            let mut new_grids = Vec::new();
+            for grid in grids {
+                let new_grid = grid.test_and_set_complete(idx as i64, *draw);
+                new_grids.push(new_grid);
+            }
+            grids = new_grids;
+            debug!("{} {:?}", draw, grids);
+        }
+        grids
+    }
+}
+
+#[derive(Debug)]
+struct Grid {
+    complete_idx: i64,
+    data: Vec<Vec<(i64, bool)>>,
+    num_row: usize,
+    num_col: usize,
+    last_draw: i64,
+}
+
+impl Grid {
+    fn new() -> Grid {
+        Grid {
+            complete_idx: -1,
+            data: Vec::new(),
+            num_row: 0,
+            num_col: 0,
+            last_draw: 0,
+        }
+    }
+
+    fn clone(&self, cell_adjuster: &dyn Fn((i64, bool), i64) -> (i64, bool)) -> Self {
+        let mut new_grid = Grid::new();
+        new_grid.complete_idx = self.complete_idx;
+        new_grid.num_row = self.data.len();
+        new_grid.last_draw = self.last_draw;
+        for row in &self.data {
+            let mut new_row = Vec::new();
+            for cell in row {
+                new_row.push(cell_adjuster(cell.to_owned(), new_grid.complete_idx));
+            }
+            new_grid.num_col = new_row.len();
+            new_grid.data.push(new_row);
+        }
+        new_grid
+    }
+
+    fn add_row(&mut self, row: &[i64]) {
+        let row: Vec<(i64, bool)> = row.iter().map(|v| (*v, false)).collect();
+        self.num_row = self.data.len();
+        self.num_col = row.len();
+        self.data.push(row);
+    }
+
+    fn mark(&self, draw: i64) -> Grid {
+        self.clone(&|cell: (i64, bool), complete_idx: i64| match cell {
+            (v, marked) if complete_idx != -1 => (v, marked),
+            (v, _marked) if v == draw => (v, true),
+            (v, marked) => (v, marked),
+        })
+    }
+
+    fn test_and_set_complete(&self, complete_idx: i64, last_draw: i64) -> Grid {
+        let mut new_grid = self.clone(&|cell, _complete_idx| cell);
+        if new_grid.complete_idx != -1 {
+            return new_grid;
+        }
+        let mut grid_win = false;
+        // Rows
+        for row in 0..new_grid.num_row {
+            let mut row_win = true;
+            for col in 0..new_grid.num_col {
+                if !new_grid.data[row][col].1 {
+                    row_win = false;
+                }
+            }
+            if row_win {
+                debug!("row_win");
+                grid_win = true;
+            }
+        }
+        // Cols
+        for col in 0..new_grid.num_col {
+            let mut col_win = true;
+            for row in 0..new_grid.num_row {
+                if !new_grid.data[row][col].1 {
+                    col_win = false;
+                }
+            }
+            if col_win {
+                debug!("col_win");
+                grid_win = true;
+            }
+        }
+
+        if grid_win {
+            new_grid.complete_idx = complete_idx;
+            new_grid.last_draw = last_draw;
+        }
+        new_grid
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn complete_index(&self) -> i64 {
+        self.complete_idx
+    }
+
+    fn score(&self) -> i64 {
+        let mut score = 0i64;
+        for row in &self.data {
+            for cell in row {
+                if !cell.1 {
+                    debug!("{:?}", cell);
+                    score += cell.0;
+                }
+            }
+        }
+        let draw_score = self.last_draw;
+        debug!("score {} x {} = {}", score, draw_score, score * draw_score);
+        score * draw_score
+    }
+}
+
+/// Parse a line of numbers, split by `separator` or (if it is `' '`) any whitespace.
+fn parse_numbers(line: &str, separator: char) -> Result<Vec<i64>, ParseError> {
+    let tokens: Box<dyn Iterator<Item = &str>> = match separator {
+        ' ' => Box::new(line.split_whitespace()),
+        _ => Box::new(line.trim_end().split(separator)),
+    };
+    tokens.map(|v| parse_at(line, v, "a number")).collect()
+}
@@ -1,22 +1,37 @@
+use anyhow::Result;
+use log::debug;
 use std::cmp::{max, min};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::num::ParseIntError;
+use std::io::BufRead;
 use std::str::FromStr;
+use utils::{parse_at, ParseError, Solver};
 
-pub fn load(filename: &str) -> Solution {
-    let file = File::open(filename).unwrap();
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
 
-    let reader = BufReader::new(file);
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut solution = Solution::new();
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let segment = LineSegment::from_str(&line).map_err(|e| e.with_line(number + 1))?;
+            solution.add(segment);
+        }
+        Ok(solution)
+    }
 
-    let mut solution = Solution::new();
-    for line in reader.lines() {
-        let line = line.unwrap().to_owned();
-        let segment = LineSegment::from_str(&line).unwrap();
-        solution.add(segment);
+    fn part1(&mut self) -> Result<Self::Answer> {
+        self.plot(false);
+        Ok(self.overlaps())
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        self.plot(true);
+        Ok(self.overlaps())
     }
-    solution
 }
 #[derive(Debug)]
 pub struct Solution {
@@ -44,9 +59,12 @@ impl Solution {
         }
     }
 
-    pub fn analyse(&mut self) {
+    /// Score every point the line segments cross, with or without the
+    /// diagonal ones.
+    fn plot(&mut self, diagonals: bool) {
+        self.scores.clear();
         for line_segment in &self.line_segments {
-            println!("{:?}", line_segment);
+            debug!("{:?}", line_segment);
 
             if line_segment.is_horizontal() {
                 let start_x = min(line_segment.start.x, line_segment.end.x);
@@ -55,7 +73,7 @@ impl Solution {
                 let end_y = max(line_segment.start.y, line_segment.end.y);
                 let y = end_y;
                 for x in start_x..=end_x {
-                    println!("({}, {})", x, y);
+                    debug!("({}, {})", x, y);
                     *self.scores.entry((x, y)).or_insert(0) += 1;
                 }
             } else if line_segment.is_vertical() {
@@ -65,10 +83,10 @@ impl Solution {
                 let end_y = max(line_segment.start.y, line_segment.end.y);
                 let x = end_x;
                 for y in start_y..=end_y {
-                    println!("({}, {})", x, y);
+                    debug!("({}, {})", x, y);
                     *self.scores.entry((x, y)).or_insert(0) += 1;
                 }
-            } else {
+            } else if diagonals {
                 let reverse = line_segment.start.x > line_segment.end.x;
                 let start_x = if reverse {
                     line_segment.end.x
@@ -90,23 +108,24 @@ impl Solution {
                 } else {
                     line_segment.end.y
                 };
-                println!("({}, {}) -> ({}, {})", start_x, start_y, end_x, end_y);
+                debug!("({}, {}) -> ({}, {})", start_x, start_y, end_x, end_y);
                 let mut delta = 1;
                 if end_y < start_y {
                     delta = -1;
                 }
                 let mut y = start_y;
                 for x in start_x..=end_x {
-                    println!("({}, {})", x, y);
+                    debug!("({}, {})", x, y);
                     *self.scores.entry((x, y)).or_insert(0) += 1;
                     y += delta;
                 }
             }
         }
-        println!("{:?}", self.scores);
+        debug!("{:?}", self.scores);
     }
 
-    pub fn answer(&self) -> i64 {
+    /// The number of points crossed by more than one line segment.
+    fn overlaps(&self) -> i64 {
         let mut total = 0;
         for score in self.scores.values() {
             if *score > 1 {
@@ -128,28 +147,30 @@ impl LineSegment {
 }
 
 impl FromStr for Point {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let coords: Vec<&str> = s.split(',').collect();
-
-        let x_fromstr = coords[0].parse::<i32>()?;
-        let y_fromstr = coords[1].parse::<i32>()?;
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::end_of_line(s, "','"))?;
 
         Ok(Point {
-            x: x_fromstr,
-            y: y_fromstr,
+            x: parse_at(s, x, "a number")?,
+            y: parse_at(s, y, "a number")?,
         })
     }
 }
 
 impl FromStr for LineSegment {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut iter = s.split(" -> ").map(Point::from_str).filter_map(|v| v.ok());
-        let start = iter.next().unwrap();
-        let end = iter.next().unwrap();
+        let s = s.trim_end();
+        let (start, end) = s
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::end_of_line(s, "\" -> \""))?;
+        let start = Point::from_str(start).map_err(|e| e.within(s, start))?;
+        let end = Point::from_str(end).map_err(|e| e.within(s, end))?;
         Ok(LineSegment { start, end })
     }
 }
@@ -0,0 +1,90 @@
+use anyhow::Result;
+use log::debug;
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::str::FromStr;
+use utils::{parse_at, ParseError, Solver};
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
+
+    fn parse<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        Ok(Solution::from_str(&line)?)
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        self.simulate(80);
+        Ok(self.count())
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        self.simulate(256);
+        Ok(self.count())
+    }
+}
+#[derive(Debug)]
+pub struct Solution {
+    population: HashMap<i32, i64>,
+}
+
+impl FromStr for Solution {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let timers = s
+            .trim()
+            .split(',')
+            .map(|v| parse_at::<i32>(s, v, "a timer"))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .fold(HashMap::new(), |mut acc, v| {
+                let counter = acc.entry(v).or_insert(0i64);
+                *counter += 1;
+                acc
+            });
+
+        Ok(Solution { population: timers })
+    }
+}
+
+impl Solution {
+    /// Age the population by `days`.
+    fn simulate(&mut self, days: u32) {
+        for days in 1..=days {
+            let mut next_population: HashMap<i32, i64> =
+                self.population
+                    .iter()
+                    .fold(HashMap::new(), |mut acc, (timer, count)| {
+                        let next_timer = match timer {
+                            0 => 6,
+                            v => v - 1,
+                        };
+                        *acc.entry(next_timer).or_insert(0) += count;
+                        acc
+                    });
+            *next_population.entry(8).or_insert(0) =
+                self.population.get(&0).unwrap_or(&0).to_owned();
+            self.population = next_population;
+            debug!("{} {:?}", days, self.count());
+        }
+    }
+
+    /// The number of lanternfish.
+    fn count(&self) -> i64 {
+        self.population
+            .iter()
+            .inspect(|&v| {
+                debug!("{:?}", v);
+            })
+            .fold(0i64, |mut acc, (_timer, count)| {
+                acc += *count;
+                acc
+            })
+    }
+}
@@ -1,8 +0,0 @@
-use d6p1::load;
-
-fn main() {
-    let mut solution = load("input.full");
-    println!("solution: {:?}", solution);
-    solution.analyse();
-    println!("answer is {}", solution.answer());
-}
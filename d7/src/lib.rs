@@ -0,0 +1,91 @@
+use anyhow::{Context, Result};
+use log::debug;
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::str::FromStr;
+use utils::{parse_at, ParseError, Solver};
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
+
+    fn parse<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        Ok(Solution::from_str(&line)?)
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        self.cheapest(|distance| distance.abs())
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        self.cheapest(|distance| self.cost(distance))
+    }
+}
+
+#[derive(Debug)]
+pub struct Solution {
+    population: HashMap<i64, i64>,
+}
+
+impl FromStr for Solution {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let positions = s
+            .trim()
+            .split(',')
+            .map(|v| parse_at::<i64>(s, v, "a position"))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .fold(HashMap::new(), |mut acc, v| {
+                let counter = acc.entry(v).or_insert(0i64);
+                *counter += 1;
+                acc
+            });
+
+        Ok(Solution {
+            population: positions,
+        })
+    }
+}
+
+impl Solution {
+    /// The least fuel needed to align every crab, where moving one crab
+    /// over a distance takes `cost` fuel.
+    fn cheapest(&self, cost: impl Fn(i64) -> i64) -> Result<i64> {
+        let mut best = -1i64;
+        let mut best_target = 0;
+        let min = self.population.keys().min().context("There are no crabs")?;
+        let max = self.population.keys().max().context("There are no crabs")?;
+        for target_position in *min..=*max {
+            let mut total = 0i64;
+            for (position, count) in &self.population {
+                total += cost(position - target_position) * count;
+            }
+            if best < 0i64 || best > total {
+                best = total;
+                best_target = target_position.to_owned();
+            }
+            debug!("{} {}", total, target_position);
+        }
+        debug!("{} {}", best, best_target);
+        Ok(best)
+    }
+
+    fn cost(&self, distance: i64) -> i64 {
+        if distance < 0 {
+            self.cost(distance.abs())
+        } else {
+            match distance {
+                0 => 0,
+                1 => 1,
+                n => n + self.cost(distance - 1),
+            }
+        }
+    }
+}
@@ -0,0 +1,325 @@
+use anyhow::{bail, Context, Result};
+use log::debug;
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+use std::str::FromStr;
+
+use itertools::Itertools;
+use utils::{ParseError, Solver};
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let mut solution = Solution::new();
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let parse = || {
+                let (patterns, output) = line
+                    .split_once('|')
+                    .ok_or_else(|| ParseError::end_of_line(&line, "'|'"))?;
+                check_entry(&line, patterns, output)?;
+                let patterns = Line::from_str(patterns).map_err(|e| e.within(&line, patterns))?;
+                let output = Line::from_str(output).map_err(|e| e.within(&line, output))?;
+                Ok::<_, ParseError>((patterns, output))
+            };
+            let (patterns, output) = parse().map_err(|e| e.with_line(number + 1))?;
+
+            debug!("{:?} => {:?}", patterns, output);
+            solution.add(patterns, output);
+        }
+
+        Ok(solution)
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        let mut count = 0;
+        for (_, output) in &self.data {
+            for single in output.entry() {
+                count += match single.len() {
+                    2 => 1, // 1 only digit with 2 segments
+                    3 => 1, // 7 only digit with 3 segments
+                    4 => 1, // 4 only digit with 4 segments
+                    7 => 1, // 8 only digit with 7 segments
+                    _ => 0,
+                };
+            }
+        }
+        Ok(count)
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        let mut total = 0;
+        for (number, entry) in self.data.iter().enumerate() {
+            total +=
+                decode(entry).with_context(|| format!("Failed to decode line {}", number + 1))?;
+        }
+        debug!("{}", total);
+        Ok(total)
+    }
+}
+
+#[derive(Debug)]
+pub struct Solution {
+    data: Vec<(Line, Line)>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Line {
+    positions: Vec<Vec<char>>,
+}
+
+impl Solution {
+    fn new() -> Self {
+        Self { data: Vec::new() }
+    }
+
+    fn add(&mut self, signal: Line, output: Line) {
+        self.data.push((signal, output));
+    }
+}
+
+/*
+1 : [ ,  , c,  ,  , f,  ] = 2
+7 : [a,  , c,  ,  , f,  ] = 3
+4 : [ , b, c, d,  , f,  ] = 4
+
+2 : [a,  , c, d, e,  , g] = 5
+3 : [a,  , c, d,  , f, g] = 5
+5 : [a, b,  , d,  , f, g] = 5
+
+0 : [a, b, c,  , e, f, g] = 6
+6 : [a, b,  , d, e, f, g] = 6
+9 : [a, b, c, d,  , f, g] = 6
+
+8 : [a, b, c, d, e, f, g] = 7
+    [8, 6, 8, 7, 4, 9, 7]
+ */
+
+/// The value shown on the output, once the input has shown which signal
+/// patterns make up which digit.
+fn decode((input, output): &(Line, Line)) -> Result<i64> {
+    let mut mapping = HashMap::new();
+    let mut r_mapping = HashMap::new();
+    for entry in input.entry() {
+        let value = match entry.len() {
+            2 => 1,
+            3 => 7,
+            4 => 4,
+            5 => -1,
+            6 => -1,
+            7 => 8,
+            _ => bail!("{} is not a digit", entry.iter().collect::<String>()),
+        };
+        if value >= 0 {
+            mapping.entry(entry.clone()).or_insert(value);
+            r_mapping.entry(value).or_insert_with(|| entry.clone());
+        }
+    }
+    let one = r_mapping
+        .get(&1)
+        .context("No signal pattern for 1")?
+        .iter()
+        .map(|v| v.to_owned())
+        .collect::<HashSet<_>>();
+    let four = r_mapping
+        .get(&4)
+        .context("No signal pattern for 4")?
+        .iter()
+        .map(|v| v.to_owned())
+        .collect::<HashSet<_>>();
+    debug!("mapping: {:?}", mapping);
+
+    for entry in input.entry() {
+        if !mapping.contains_key(entry) {
+            let this = entry.iter().map(|v| v.to_owned()).collect::<HashSet<_>>();
+            let value = match entry.len() {
+                6 => match (
+                    this.intersection(&four).count(),
+                    this.intersection(&one).count(),
+                ) {
+                    (4, _) => 9,
+                    (_, 2) => 0,
+                    _ => 6,
+                },
+                5 => match (
+                    this.intersection(&one).count(),
+                    this.intersection(&four).count(),
+                ) {
+                    (2, _) => 3,
+                    (_, 2) => 2,
+                    _ => 5,
+                },
+                _ => bail!("{} is not a digit", entry.iter().collect::<String>()),
+            };
+            if value >= 0 {
+                mapping.entry(entry.clone()).or_insert(value);
+            }
+        }
+    }
+
+    debug!("mapping: {:?}", mapping);
+
+    let mut line_score = 0;
+    for entry in output.entry() {
+        let digit = mapping.get(entry).with_context(|| {
+            format!(
+                "{} is not one of the signal patterns",
+                entry.iter().collect::<String>()
+            )
+        })?;
+        line_score *= 10;
+        line_score += digit;
+    }
+    Ok(line_score)
+}
+
+impl Line {
+    fn entry(&self) -> impl Iterator<Item = &Vec<char>> + '_ {
+        self.positions.iter()
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let positions = s
+            .split_whitespace()
+            .map(|v| segments(s, v).map(|v| v.chars().sorted().collect::<Vec<_>>()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Line { positions })
+    }
+}
+
+/// Check that `patterns` and `output`, the two halves of `line`, hold ten
+/// signal patterns, including those for 1 and 4, and four output digits, each
+/// lighting from two to seven different segments.
+fn check_entry(line: &str, patterns: &str, output: &str) -> Result<(), ParseError> {
+    let bar = &line[patterns.len()..patterns.len() + 1];
+    let signals = patterns.split_whitespace().collect::<Vec<_>>();
+    if signals.len() < 10 {
+        return Err(ParseError::at(line, bar, "10 signal patterns"));
+    }
+    if let Some(extra) = signals.get(10) {
+        return Err(ParseError::at(line, extra, "'|'"));
+    }
+    let digits = output.split_whitespace().collect::<Vec<_>>();
+    if digits.len() < 4 {
+        return Err(ParseError::end_of_line(line, "4 output digits"));
+    }
+    if let Some(extra) = digits.get(4) {
+        return Err(ParseError::at(line, extra, "end of line"));
+    }
+    for pattern in signals.iter().chain(&digits) {
+        let distinct = pattern.chars().collect::<HashSet<_>>().len();
+        if !(2..=7).contains(&pattern.len()) || distinct != pattern.len() {
+            return Err(ParseError::at(
+                line,
+                pattern,
+                "a pattern of 2 to 7 different segments",
+            ));
+        }
+    }
+    for (digit, segments) in [(1, 2), (4, 4)] {
+        if !signals.iter().any(|pattern| pattern.len() == segments) {
+            let expected = format!("a signal pattern for {}", digit);
+            return Err(ParseError::at(line, bar, expected));
+        }
+    }
+    Ok(())
+}
+
+/// Check that `pattern`, a slice of `line`, only lights segments `a` to `g`.
+fn segments<'a>(line: &str, pattern: &'a str) -> Result<&'a str, ParseError> {
+    match pattern
+        .char_indices()
+        .find(|(_, c)| !('a'..='g').contains(c))
+    {
+        Some((i, c)) => Err(ParseError::at(
+            line,
+            &pattern[i..i + c.len_utf8()],
+            "a segment from a to g",
+        )),
+        None => Ok(pattern),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = "\
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+";
+
+    #[test]
+    fn example() {
+        let mut solution = Solution::parse_str(EXAMPLE).unwrap();
+        assert_eq!(solution.part1().unwrap(), 26);
+        assert_eq!(solution.part2().unwrap(), 61229);
+    }
+
+    #[test]
+    fn malformed_entries() {
+        let error = |input: &str| Solution::parse_str(input).unwrap_err().to_string();
+        let patterns = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab";
+        assert_eq!(
+            error("be | cf\n"),
+            "line 1, column 4: expected 10 signal patterns, found \"|\""
+        );
+        assert_eq!(
+            error("a b | c\n"),
+            "line 1, column 5: expected 10 signal patterns, found \"|\""
+        );
+        assert_eq!(
+            error(&format!("{} ab | cdfeb fcadb cdfeb\n", patterns)),
+            "line 1, column 60: expected '|', found \"ab\""
+        );
+        assert_eq!(
+            error(&format!("{} | cdfeb fcadb cdfeb\n", patterns)),
+            "line 1, column 79: expected 4 output digits, found end of line"
+        );
+        assert_eq!(
+            error(&format!("{} | cdfeb fcadb cdfeb cdbaf cdbaf\n", patterns)),
+            "line 1, column 86: expected end of line, found \"cdbaf\""
+        );
+        assert_eq!(
+            error(&format!("{} | cdfeb fcadb ccdfeb a\n", patterns)),
+            "line 1, column 74: expected a pattern of 2 to 7 different segments, found \"ccdfeb\""
+        );
+        assert_eq!(
+            error(&format!(
+                "{} | cdfeb fcadb cdfeb cdbaf\n",
+                patterns.replace("eafb", "eafbg")
+            )),
+            "line 1, column 61: expected a signal pattern for 4, found \"|\""
+        );
+    }
+
+    #[test]
+    fn unknown_output() {
+        let mut solution = Solution::parse_str(
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb abcde\n",
+        )
+        .unwrap();
+        assert_eq!(
+            format!("{:#}", solution.part2().unwrap_err()),
+            "Failed to decode line 1: abcde is not one of the signal patterns"
+        );
+    }
+}
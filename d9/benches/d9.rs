@@ -0,0 +1,10 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use d9::Solution;
+
+fn bench(c: &mut Criterion) {
+    utils::bench::solver::<Solution>(c, 9, 1);
+    utils::bench::solver::<Solution>(c, 9, 2);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,108 @@
+use anyhow::Result;
+use log::debug;
+use std::io::BufRead;
+use std::str::FromStr;
+use utils::{search, Grid, Neighbourhood, Solver};
+
+use itertools::Itertools;
+
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
+
+    fn parse<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        let grid = Grid::<u8>::from_str(&input)?;
+
+        Ok(Solution::new(grid.map(|v| *v as i64)))
+    }
+
+    fn part1(&mut self) -> Result<Self::Answer> {
+        let mut risk = 0;
+        for (x, y) in self.low_points() {
+            let height = self.data.get(x, y).unwrap();
+            debug!("({}, {}) {}", x, y, height);
+            risk += 1 + height;
+        }
+        Ok(risk)
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        let mut sizes = Vec::new();
+        for (x, y) in self.low_points() {
+            // A basin is everything that flows down to its low point
+            let points = search::reachable(&(x, y), |&(x, y)| {
+                self.data
+                    .neighbours(x, y, Neighbourhood::Orthogonal)
+                    .filter(|(_, h)| **h != 9)
+                    .map(|(point, _)| point)
+                    .collect::<Vec<_>>()
+            });
+            debug!("({},{}) points {} = {:?}", x, y, points.len(), points);
+            sizes.push(points.len());
+        }
+        let answer = sizes
+            .iter()
+            .sorted()
+            .rev()
+            .take(3)
+            .fold(1, |acc, v| acc * *v as i64);
+        debug!("{}", answer);
+        Ok(answer)
+    }
+}
+
+#[derive(Debug)]
+pub struct Solution {
+    data: Grid<i64>,
+}
+
+impl Solution {
+    fn new(data: Grid<i64>) -> Self {
+        Self { data }
+    }
+
+    /// The points lower than all of their neighbours.
+    fn low_points(&self) -> Vec<(isize, isize)> {
+        let mut seed = Vec::new();
+        let (width, height) = (self.data.width() as isize, self.data.height() as isize);
+        for y in 0..height {
+            for x in 0..width {
+                let height = self.data.get(x, y).unwrap();
+                let lowest = self
+                    .data
+                    .neighbours(x, y, Neighbourhood::Orthogonal)
+                    .all(|(_, h)| h > height);
+                if lowest {
+                    seed.push((x, y));
+                }
+            }
+        }
+        debug!("seed {:?}", seed);
+        seed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const EXAMPLE: &str = "\
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678
+";
+
+    #[test]
+    fn example() {
+        let mut solution = Solution::parse_str(EXAMPLE).unwrap();
+        assert_eq!(solution.part1().unwrap(), 15);
+        assert_eq!(solution.part2().unwrap(), 1134);
+    }
+}
@@ -0,0 +1,10 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use template::Solution;
+
+fn bench(c: &mut Criterion) {
+    utils::bench::solver::<Solution>(c, 0, 1);
+    utils::bench::solver::<Solution>(c, 0, 2);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -1,16 +1,28 @@
 use anyhow::Result;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
+use utils::Solver;
 
-pub fn load(filename: &str) -> anyhow::Result<Solution> {
-    let file = File::open(filename)?;
+pub fn load(filename: &str) -> Result<Solution> {
+    Solution::load(filename)
+}
+
+impl Solver for Solution {
+    type Answer = i64;
 
-    let mut reader = BufReader::new(file);
+    fn parse<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
 
-    let mut line = String::new();
-    reader.read_line(&mut line)?;
+        Ok(Solution::new())
+    }
 
-    Ok(Solution::new())
+    fn part1(&mut self) -> Result<Self::Answer> {
+        Ok(0)
+    }
+
+    fn part2(&mut self) -> Result<Self::Answer> {
+        Ok(0)
+    }
 }
 
 #[derive(Debug)]
@@ -20,10 +32,22 @@ impl Solution {
     fn new() -> Self {
         Self {}
     }
+}
 
-    pub fn analyse(&mut self) {}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    pub fn answer(&self) -> Result<i64> {
-        Ok(0)
+    #[ctor::ctor]
+    fn init() {
+        env_logger::init();
+    }
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    fn example() {
+        let mut solution = Solution::parse_str(EXAMPLE).unwrap();
+        assert_eq!(solution.part1().unwrap(), 0);
     }
 }
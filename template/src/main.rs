@@ -1,24 +1,14 @@
 use anyhow::Result;
 use log::info;
-use template::load;
-use yansi::Paint;
+use template::Solution;
+use utils::Solver;
 
 fn main() -> Result<()> {
     env_logger::init();
 
-    let mut solution = load("input.full")?;
-    info!(
-        "{}{}: {:?}",
-        Paint::masked("🎄 "),
-        Paint::bold(Paint::yellow("solution")),
-        solution
-    );
-    solution.analyse();
-    info!(
-        "{}answer is {}",
-        Paint::masked("🎅 "),
-        Paint::bold(Paint::red(solution.answer()?))
-    );
+    let (part, mut solution) = Solution::from_args(0)?;
+    info!("solution: {:?}", solution);
+    info!("answer is {}", solution.solve(part)?);
 
     Ok(())
 }
@@ -0,0 +1,42 @@
+use crate::input::Inputs;
+use crate::Solver;
+use criterion::{BatchSize, Criterion};
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Benchmark parsing and solving the full input for one day's part.
+///
+/// Results are reported as `dNpM/parse` and `dNpM/solve`, which is the
+/// layout `aoc report` expects to find under `target/criterion`. Days whose
+/// input cannot be found or fetched are skipped.
+pub fn solver<S: Solver>(c: &mut Criterion, day: u32, part: u32) {
+    let mut inputs = Inputs::from_env();
+    if env::var_os("AOC_INPUTS").is_none() {
+        // Benchmarks run from their own crate directory.
+        inputs = inputs.with_cache_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs"));
+    }
+    let input = match inputs
+        .resolve(day)
+        .and_then(|path| Ok(fs::read_to_string(path)?))
+    {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Skipping day {} part {}: {:#}", day, part, e);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("d{}p{}", day, part));
+    group.bench_function("parse", |b| b.iter(|| S::parse_str(&input).unwrap()));
+    // Some days take seconds to solve, so keep to the minimum sample count.
+    group.sample_size(10);
+    group.bench_function("solve", |b| {
+        b.iter_batched(
+            || S::parse_str(&input).unwrap(),
+            |mut solver| solver.solve(part).unwrap(),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
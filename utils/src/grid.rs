@@ -0,0 +1,411 @@
+use crate::{Neighbourhood, ParseError};
+use anyhow::{bail, Result};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// A dense, rectangular grid of cells, stored row by row.
+///
+/// Coordinates are `(x, y)`, with `(0, 0)` at the top left. Lookups take
+/// signed coordinates so that neighbours can be probed without first checking
+/// for underflow; anything outside the grid is simply `None`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Build a `width` by `height` grid, calling `f(x, y)` for every cell.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let data = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Self {
+            data,
+            width,
+            height,
+        }
+    }
+
+    /// Build a grid from rows of cells, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+        let mut data = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                bail!(
+                    "Row {} has {} cells, expected {} like the first row",
+                    y + 1,
+                    row.len(),
+                    width
+                );
+            }
+            data.extend(row);
+        }
+        Ok(Self {
+            data,
+            width,
+            height,
+        })
+    }
+
+    /// Build a grid from text, converting each character with `f`, which
+    /// returns `None` for anything that is not the `expected` kind of cell.
+    ///
+    /// Blank lines are skipped, and every other line must be as wide as the first.
+    pub fn parse_with(
+        s: &str,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut data = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, raw) in s.lines().enumerate() {
+            let line = raw.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let mut cells = 0;
+            for (i, c) in line.char_indices() {
+                let cell = f(c).ok_or_else(|| {
+                    ParseError::at(raw, &line[i..i + c.len_utf8()], expected).with_line(y + 1)
+                })?;
+                data.push(cell);
+                cells += 1;
+            }
+            match width {
+                None => width = Some(cells),
+                Some(width) if cells < width => {
+                    return Err(ParseError::end_of_line(raw.trim_end(), expected).with_line(y + 1));
+                }
+                Some(width) if cells > width => {
+                    let (extra, _) = line.char_indices().nth(width).unwrap_or_default();
+                    return Err(ParseError::at(raw, &line[extra..], "end of line").with_line(y + 1));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Self {
+            data,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn offset(&self, x: isize, y: isize) -> Option<usize> {
+        let x = usize::try_from(x).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(y).ok().filter(|y| *y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        self.offset(x, y).is_some()
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        self.offset(x, y).map(|offset| &self.data[offset])
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        self.offset(x, y).map(|offset| &mut self.data[offset])
+    }
+
+    /// Store `value` at `(x, y)`, returning false if that is outside the grid.
+    pub fn set(&mut self, x: isize, y: isize, value: T) -> bool {
+        match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Map any coordinates back onto the grid, as if it were a torus, unless
+    /// the grid is empty and there is nothing to map them onto.
+    pub fn wrap(&self, x: isize, y: isize) -> Option<(isize, isize)> {
+        (self.width > 0 && self.height > 0).then(|| {
+            (
+                x.rem_euclid(self.width as isize),
+                y.rem_euclid(self.height as isize),
+            )
+        })
+    }
+
+    /// The neighbours of `(x, y)` that lie within the grid, with their coordinates.
+    pub fn neighbours(
+        &self,
+        x: isize,
+        y: isize,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = ((isize, isize), &T)> + '_ {
+        neighbourhood
+            .around(x, y)
+            .filter_map(move |(x, y)| self.get(x, y).map(|cell| ((x, y), cell)))
+    }
+
+    /// The neighbours of `(x, y)`, wrapping around the edges of the grid.
+    ///
+    /// On grids narrower than three cells the same neighbour can be reached
+    /// from both sides, and so is yielded more than once. Empty grids have no
+    /// neighbours.
+    pub fn wrapping_neighbours(
+        &self,
+        x: isize,
+        y: isize,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = ((isize, isize), &T)> + '_ {
+        neighbourhood.around(x, y).filter_map(move |(x, y)| {
+            let (x, y) = self.wrap(x, y)?;
+            Some(((x, y), &self[(x as usize, y as usize)]))
+        })
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.data[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        // `chunks` rejects a zero size, but then there is nothing to yield anyway.
+        self.data.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        let cells = if x < self.width { self.height } else { 0 };
+        self.data
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(cells)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Every cell, along with its `(x, y)` coordinates, in row order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        let width = self.width;
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(offset, cell)| ((offset % width, offset / width), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> + '_ {
+        let width = self.width;
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(offset, cell)| ((offset % width, offset / width), cell))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            data: self.data.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the grid",
+            x,
+            y
+        );
+        &self.data[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the grid",
+            x,
+            y
+        );
+        &mut self.data[y * self.width + x]
+    }
+}
+
+/// A grid of single digits, such as a height map.
+impl FromStr for Grid<u8> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, "a digit", |c| c.to_digit(10).map(|d| d as u8))
+    }
+}
+
+/// A grid of characters, such as a map of `#` and `.`.
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, "a character", Some)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEIGHTS: &str = "2199943210\n3987894921\n9856789892\n8767896789\n9899965678\n";
+
+    #[test]
+    fn digits() {
+        let grid = Grid::<u8>::from_str(HEIGHTS).unwrap();
+        assert_eq!(grid.width(), 10);
+        assert_eq!(grid.height(), 5);
+        assert_eq!(grid.get(0, 0), Some(&2));
+        assert_eq!(grid.get(9, 4), Some(&8));
+        assert_eq!(grid.get(10, 0), None);
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid[(2, 1)], 8);
+        assert_eq!(grid.to_string(), HEIGHTS);
+    }
+
+    #[test]
+    fn chars() {
+        let grid = Grid::<char>::from_str("#.\n.#\n").unwrap();
+        assert_eq!(grid.iter().filter(|(_, c)| **c == '#').count(), 2);
+        assert_eq!(grid.to_string(), "#.\n.#\n");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = Grid::<u8>::from_str("12\n3x\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected a digit, found \"x\""
+        );
+        let error = Grid::<u8>::from_str("12\n\n3\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 2: expected a digit, found end of line"
+        );
+        let error = Grid::<u8>::from_str("12\n345\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected end of line, found \"5\""
+        );
+        let error = Grid::from_rows(vec![vec![1, 2], vec![3]]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Row 2 has 1 cells, expected 2 like the first row"
+        );
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::from_fn(3, 2, |x, y| x + 10 * y);
+        assert_eq!(grid.row(1), Some(&[10, 11, 12][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![2, 12]);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(
+            grid.columns().map(|c| c.sum::<usize>()).collect::<Vec<_>>(),
+            vec![10, 12, 14]
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::<u8>::from_str(HEIGHTS).unwrap();
+        let around = |x, y, neighbourhood| {
+            grid.neighbours(x, y, neighbourhood)
+                .map(|(point, height)| (point, *height))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            around(1, 1, Neighbourhood::Orthogonal),
+            vec![((1, 0), 1), ((1, 2), 8), ((0, 1), 3), ((2, 1), 8)]
+        );
+        assert_eq!(
+            around(0, 0, Neighbourhood::Orthogonal),
+            vec![((0, 1), 3), ((1, 0), 1)]
+        );
+        assert_eq!(around(1, 1, Neighbourhood::WithDiagonals).len(), 8);
+        assert_eq!(
+            around(9, 4, Neighbourhood::WithDiagonals),
+            vec![((8, 3), 8), ((9, 3), 9), ((8, 4), 7)]
+        );
+    }
+
+    #[test]
+    fn wrapping_neighbours() {
+        let grid = Grid::<u8>::from_str(HEIGHTS).unwrap();
+        assert_eq!(grid.wrap(-1, 5), Some((9, 0)));
+        assert_eq!(grid.wrap(23, -7), Some((3, 3)));
+        let around = grid
+            .wrapping_neighbours(0, 0, Neighbourhood::Orthogonal)
+            .map(|(point, height)| (point, *height))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            around,
+            vec![((0, 4), 9), ((0, 1), 3), ((9, 0), 0), ((1, 0), 1)]
+        );
+        assert_eq!(
+            grid.wrapping_neighbours(9, 4, Neighbourhood::WithDiagonals)
+                .count(),
+            8
+        );
+
+        let empty = Grid::<u8>::from_str("").unwrap();
+        assert_eq!(empty.wrap(0, 0), None);
+        assert_eq!(
+            empty
+                .wrapping_neighbours(0, 0, Neighbourhood::WithDiagonals)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn update() {
+        let mut grid = Grid::from_fn(2, 2, |_, _| 0);
+        assert!(grid.set(1, 1, 5));
+        assert!(!grid.set(2, 1, 5));
+        *grid.get_mut(0, 1).unwrap() += 3;
+        grid[(1, 0)] = 7;
+        assert_eq!(grid.map(|v| v * 2).to_string(), "014\n610\n");
+        for (_, cell) in grid.iter_mut() {
+            *cell += 1;
+        }
+        assert_eq!(grid.to_string(), "18\n46\n");
+    }
+}
@@ -0,0 +1,206 @@
+use anyhow::{bail, Context, Result};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const YEAR: u32 = 2021;
+const BASE_URL: &str = "https://adventofcode.com";
+
+/// Locates puzzle inputs in a local cache, downloading any that are missing.
+///
+/// Inputs are cached as `<cache_dir>/2021/dayN.txt`, so each one is only ever
+/// fetched once.
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    cache_dir: PathBuf,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Inputs {
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+            base_url: BASE_URL.to_string(),
+            session: None,
+        }
+    }
+
+    /// Configure from the environment.
+    ///
+    /// * `AOC_INPUTS` - cache directory, defaulting to `inputs`
+    /// * `AOC_BASE_URL` - server to fetch from, defaulting to the real site
+    /// * `AOC_SESSION` - session cookie, otherwise read from `~/.config/aoc/session`
+    pub fn from_env() -> Self {
+        let cache_dir = env::var("AOC_INPUTS").unwrap_or_else(|_| "inputs".to_string());
+        let mut inputs = Self::new(cache_dir);
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            inputs = inputs.with_base_url(base_url);
+        }
+        if let Some(session) = session_from_env() {
+            inputs = inputs.with_session(session);
+        }
+        inputs
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn with_session(mut self, session: impl Into<String>) -> Self {
+        self.session = Some(session.into());
+        self
+    }
+
+    pub fn with_cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = cache_dir.into();
+        self
+    }
+
+    /// Where the input for `day` is cached, whether or not it exists yet.
+    pub fn path(&self, day: u32) -> PathBuf {
+        self.cache_dir
+            .join(YEAR.to_string())
+            .join(format!("day{}.txt", day))
+    }
+
+    /// The path to the input for `day`, fetching it first if it is not cached.
+    pub fn resolve(&self, day: u32) -> Result<PathBuf> {
+        let path = self.path(day);
+        if !path.is_file() {
+            self.fetch(day, &path)?;
+        }
+        Ok(path)
+    }
+
+    fn fetch(&self, day: u32, path: &Path) -> Result<()> {
+        if !(1..=25).contains(&day) {
+            bail!("No puzzle for day {}", day);
+        }
+        let session = self.session.as_ref().with_context(|| {
+            format!(
+                "Input for day {} is not cached at {}, and no session token is set (AOC_SESSION)",
+                day,
+                path.display()
+            )
+        })?;
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        let body = match ureq::get(&url)
+            .set("Cookie", &format!("session={}", session.trim()))
+            .call()
+        {
+            Ok(response) => response.into_string()?,
+            Err(ureq::Error::Status(code, _)) => {
+                bail!("Fetching {} failed with status {}", url, code)
+            }
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to fetch input for day {}", day))
+            }
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        fs::write(path, body).with_context(|| format!("Failed to write to {}", path.display()))
+    }
+}
+
+fn session_from_env() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session);
+    }
+    let home = env::var_os("HOME")?;
+    let config = Path::new(&home).join(".config").join("aoc").join("session");
+    fs::read_to_string(config).ok()
+}
+
+/// The path to the input for `day`, using the configuration in the environment.
+pub fn resolve(day: u32) -> Result<PathBuf> {
+    Inputs::from_env().resolve(day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serve a single canned HTTP response, returning the base URL and a
+    /// handle yielding the request line and headers that were received.
+    fn stand_in(status: &str, body: &str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = BufReader::new(stream.try_clone().unwrap())
+                .lines()
+                .map(|line| line.unwrap())
+                .take_while(|line| !line.is_empty())
+                .collect();
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn fetches_missing_input_once() {
+        let cache = tempfile::tempdir().unwrap();
+        let (base_url, server) = stand_in("200 OK", "1\n2\n3\n");
+        let inputs = Inputs::new(cache.path())
+            .with_base_url(base_url)
+            .with_session("token");
+
+        let path = inputs.resolve(15).unwrap();
+        assert_eq!(path, cache.path().join("2021").join("day15.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2021/day/15/input HTTP/1.1");
+        assert!(request.iter().any(|line| line == "Cookie: session=token"));
+
+        // The stand-in has gone away, so this can only succeed from the cache.
+        assert_eq!(inputs.resolve(15).unwrap(), path);
+    }
+
+    #[test]
+    fn cached_input_needs_no_session() {
+        let cache = tempfile::tempdir().unwrap();
+        let inputs = Inputs::new(cache.path());
+        fs::create_dir_all(cache.path().join("2021")).unwrap();
+        fs::write(inputs.path(1), "199\n").unwrap();
+
+        assert_eq!(inputs.resolve(1).unwrap(), inputs.path(1));
+    }
+
+    #[test]
+    fn missing_session() {
+        let cache = tempfile::tempdir().unwrap();
+        let error = Inputs::new(cache.path()).resolve(3).unwrap_err();
+        assert!(error.to_string().contains("no session token"));
+    }
+
+    #[test]
+    fn http_error_is_not_cached() {
+        let cache = tempfile::tempdir().unwrap();
+        let (base_url, server) = stand_in("400 Bad Request", "Please log in");
+        let inputs = Inputs::new(cache.path())
+            .with_base_url(base_url)
+            .with_session("expired");
+
+        let error = inputs.resolve(7).unwrap_err();
+        server.join().unwrap();
+        assert!(error.to_string().contains("status 400"));
+        assert!(!inputs.path(7).exists());
+    }
+}
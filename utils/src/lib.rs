@@ -1,6 +1,23 @@
 use std::cmp::max;
 use std::collections::HashMap;
 
+#[cfg(feature = "bench")]
+pub mod bench;
+mod grid;
+pub mod input;
+mod neighbours;
+mod parse;
+pub mod search;
+mod solver;
+
+pub use grid::Grid;
+pub use neighbours::Neighbourhood;
+pub use parse::{parse_at, ParseError};
+pub use solver::Solver;
+
+/// A sparse grid, for points scattered over an unbounded area.
+///
+/// Dense, rectangular grids are better held in a [`Grid`].
 #[derive(Debug, Default)]
 pub struct Matrix {
     data: HashMap<(isize, isize), i64>,
@@ -28,6 +45,18 @@ impl Matrix {
     pub fn dimensions(&self) -> (isize, isize) {
         (self.max_x, self.max_y)
     }
+
+    /// The points around `(x, y)` which hold a value.
+    pub fn neighbours(
+        &self,
+        x: isize,
+        y: isize,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = ((isize, isize), &i64)> + '_ {
+        neighbourhood
+            .around(x, y)
+            .filter_map(move |(x, y)| self.get(x, y).map(|value| ((x, y), value)))
+    }
 }
 
 #[cfg(test)]
@@ -47,4 +76,23 @@ mod tests {
         let result = matrix.get(1, 1);
         assert_eq!(result, Some(&1i64));
     }
+
+    #[test]
+    fn matrix_neighbours() {
+        let mut matrix = Matrix::new();
+        matrix.set(0, 0, 1);
+        matrix.set(1, 0, 2);
+        matrix.set(1, 1, 3);
+        let around = |neighbourhood| {
+            matrix
+                .neighbours(0, 0, neighbourhood)
+                .map(|(point, value)| (point, *value))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(around(Neighbourhood::Orthogonal), vec![((1, 0), 2)]);
+        assert_eq!(
+            around(Neighbourhood::WithDiagonals),
+            vec![((1, 0), 2), ((1, 1), 3)]
+        );
+    }
 }
@@ -0,0 +1,35 @@
+/// Which of the cells around a point count as its neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// The four cells above, below, left and right.
+    Orthogonal,
+    /// All eight surrounding cells, including the diagonals.
+    WithDiagonals,
+}
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+const WITH_DIAGONALS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl Neighbourhood {
+    /// The `(dx, dy)` steps from a point to each of its neighbours.
+    pub fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Neighbourhood::Orthogonal => &ORTHOGONAL,
+            Neighbourhood::WithDiagonals => &WITH_DIAGONALS,
+        }
+    }
+
+    /// The coordinates of every neighbour of `(x, y)`, unbounded.
+    pub fn around(self, x: isize, y: isize) -> impl Iterator<Item = (isize, isize)> {
+        self.offsets().iter().map(move |(dx, dy)| (x + dx, y + dy))
+    }
+}
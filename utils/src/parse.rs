@@ -0,0 +1,208 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A malformed puzzle input: where the problem is, and what should have been there.
+///
+/// Errors are usually raised while parsing a single line, which does not know
+/// where it sits in the input, so loaders attach the line number afterwards
+/// with [`ParseError::with_line`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: Option<usize>,
+    column: usize,
+    expected: String,
+    found: Found,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Found {
+    Text(String),
+    EndOfLine,
+    EndOfInput,
+}
+
+impl ParseError {
+    /// `found` was at (1-based) `column`, where `expected` should have been.
+    pub fn new(column: usize, expected: impl Into<String>, found: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            column,
+            expected: expected.into(),
+            found: Found::Text(found.into()),
+        }
+    }
+
+    /// `token`, a slice of `line`, is not the `expected` token.
+    pub fn at(line: &str, token: &str, expected: impl Into<String>) -> Self {
+        Self::new(column_of(line, token), expected, token)
+    }
+
+    /// The text at the start of `rest`, the unparsed tail of `line`, is not the
+    /// `expected` token.
+    pub fn at_start(line: &str, rest: &str, expected: impl Into<String>) -> Self {
+        match rest.chars().next() {
+            Some(c) => Self::at(line, &rest[..c.len_utf8()], expected),
+            None => Self::end_of_line(line, expected),
+        }
+    }
+
+    /// `line` finished before the `expected` token.
+    pub fn end_of_line(line: &str, expected: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            column: line.chars().count() + 1,
+            expected: expected.into(),
+            found: Found::EndOfLine,
+        }
+    }
+
+    /// The input finished before the `expected` line. Loaders should record
+    /// the line after the last one with [`ParseError::with_line`].
+    pub fn end_of_input(expected: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            column: 1,
+            expected: expected.into(),
+            found: Found::EndOfInput,
+        }
+    }
+
+    /// Move an error raised while parsing `part`, a slice of `line`, to
+    /// `line`'s columns.
+    pub fn within(mut self, line: &str, part: &str) -> Self {
+        self.column += column_of(line, part) - 1;
+        self
+    }
+
+    /// Record which (1-based) line of the input the error is on.
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// The offending text, or `None` if the line or input ended early.
+    pub fn found(&self) -> Option<&str> {
+        match &self.found {
+            Found::Text(found) => Some(found),
+            Found::EndOfLine | Found::EndOfInput => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {}, ", line)?;
+        }
+        write!(
+            f,
+            "column {}: expected {}, found ",
+            self.column, self.expected
+        )?;
+        match &self.found {
+            Found::Text(found) => write!(f, "{:?}", found),
+            Found::EndOfLine => write!(f, "end of line"),
+            Found::EndOfInput => write!(f, "end of input"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse `token`, a slice of `line`, reporting where it is if it is not the
+/// `expected` value.
+pub fn parse_at<T: FromStr>(line: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(line, token, expected))
+}
+
+/// The 1-based character column at which `token` starts within `line`.
+fn column_of(line: &str, token: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize)
+        .checked_sub(start)
+        .filter(|offset| offset + token.len() <= line.len())
+        .or_else(|| line.find(token))
+        .unwrap_or(0);
+    line[..offset].chars().count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_from_slice() {
+        let line = "forward 5";
+        let (_, amount) = line.split_once(' ').unwrap();
+        let error = ParseError::at(line, &amount[..1], "a direction");
+        assert_eq!(error.column(), 9);
+        assert_eq!(error.found(), Some("5"));
+    }
+
+    #[test]
+    fn column_from_copy() {
+        let error = ParseError::at("up x", &String::from("x"), "a number");
+        assert_eq!(error.column(), 4);
+    }
+
+    #[test]
+    fn next_token() {
+        let line = "[1,2";
+        let error = ParseError::at_start(line, &line[2..], "','");
+        assert_eq!((error.column(), error.found()), (3, Some(",")));
+        let error = ParseError::at_start(line, &line[4..], "']'");
+        assert_eq!((error.column(), error.found()), (5, None));
+    }
+
+    #[test]
+    fn within_line() {
+        let line = "0,9 -> 5,x";
+        let (_, end) = line.split_once(" -> ").unwrap();
+        let error = parse_at::<i32>(end, &end[2..], "a number").unwrap_err();
+        assert_eq!(error.column(), 3);
+        assert_eq!(error.within(line, end).column(), 10);
+    }
+
+    #[test]
+    fn display() {
+        let error = ParseError::new(3, "a number", "x");
+        assert_eq!(
+            error.to_string(),
+            "column 3: expected a number, found \"x\""
+        );
+        let error = ParseError::end_of_line("down", "a number").with_line(7);
+        assert_eq!(
+            error.to_string(),
+            "line 7, column 5: expected a number, found end of line"
+        );
+        let error = ParseError::end_of_input("player 2").with_line(2);
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected player 2, found end of input"
+        );
+    }
+
+    #[test]
+    fn parse_tokens() {
+        let line = "x=20..30";
+        assert_eq!(parse_at::<i64>(line, &line[2..4], "a number"), Ok(20));
+        let error = parse_at::<i64>(line, &line[4..6], "a number").unwrap_err();
+        assert_eq!(error.column(), 5);
+        assert_eq!(error.expected(), "a number");
+    }
+}
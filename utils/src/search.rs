@@ -0,0 +1,369 @@
+//! Searches over a graph described by a successor function.
+//!
+//! Nodes are never listed up front: each search starts from a node, and asks
+//! `successors` for the nodes reachable in one step, so any state space can be
+//! searched as long as its nodes can be hashed.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The cheapest path from `start` to a node satisfying `success`, and its cost.
+///
+/// `successors` yields each neighbouring node along with the (non-negative)
+/// cost of stepping to it. The path includes both `start` and the goal.
+pub fn dijkstra<N, C, FN, IN, FS>(start: &N, successors: FN, success: FS) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+/// As [`dijkstra`], guided by a `heuristic` which must never overestimate the
+/// remaining cost to a goal.
+pub fn astar<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut nodes = Nodes::new(start);
+    // Best known cost to reach each node, indexed like `nodes`
+    let mut costs = vec![C::default()];
+    let mut queue = BinaryHeap::new();
+    queue.push(Candidate {
+        estimate: heuristic(start),
+        cost: C::default(),
+        node: 0,
+    });
+
+    while let Some(Candidate { cost, node, .. }) = queue.pop() {
+        if cost > costs[node] {
+            // Superseded by a cheaper route found after this was queued
+            continue;
+        }
+        if success(&nodes.nodes[node]) {
+            return Some((nodes.path(node), cost));
+        }
+        for (next, step) in successors(&nodes.nodes[node]) {
+            let next_cost = cost + step;
+            let next = match nodes.index.get(&next) {
+                Some(&index) if next_cost >= costs[index] => continue,
+                Some(&index) => {
+                    costs[index] = next_cost;
+                    nodes.parents[index] = node;
+                    index
+                }
+                None => {
+                    costs.push(next_cost);
+                    nodes.insert(next, node)
+                }
+            };
+            queue.push(Candidate {
+                estimate: next_cost + heuristic(&nodes.nodes[next]),
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+    None
+}
+
+/// The path with the fewest steps from `start` to a node satisfying `success`.
+pub fn bfs<N, FN, IN, FS>(start: &N, mut successors: FN, mut success: FS) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut nodes = Nodes::new(start);
+    let mut queue = VecDeque::from([0]);
+    while let Some(node) = queue.pop_front() {
+        if success(&nodes.nodes[node]) {
+            return Some(nodes.path(node));
+        }
+        for next in successors(&nodes.nodes[node]) {
+            if !nodes.index.contains_key(&next) {
+                queue.push_back(nodes.insert(next, node));
+            }
+        }
+    }
+    None
+}
+
+/// Every node reachable from `start`, including `start` itself: a flood fill.
+pub fn reachable<N, FN, IN>(start: &N, mut successors: FN) -> HashSet<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut pending = vec![start.clone()];
+    while let Some(node) = pending.pop() {
+        for next in successors(&node) {
+            if seen.insert(next.clone()) {
+                pending.push(next);
+            }
+        }
+    }
+    seen
+}
+
+/// Split `nodes` into groups which are connected to each other.
+///
+/// `successors` must be symmetric: if `b` follows `a`, then `a` follows `b`.
+/// Groups are returned in the order their first node appears in `nodes`.
+pub fn connected_components<N, FN, IN>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: FN,
+) -> Vec<HashSet<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut components: Vec<HashSet<N>> = Vec::new();
+    for node in nodes {
+        if components
+            .iter()
+            .all(|component| !component.contains(&node))
+        {
+            components.push(reachable(&node, &mut successors));
+        }
+    }
+    components
+}
+
+/// The number of distinct paths from `start` to nodes satisfying `success`.
+///
+/// Paths stop at the first goal they reach. The graph must be acyclic, which
+/// is usually arranged by making the nodes record whatever a path has used up.
+pub fn count_paths<N, FN, IN, FS>(start: &N, mut successors: FN, mut success: FS) -> usize
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    fn count<N, FN, IN, FS>(
+        node: &N,
+        successors: &mut FN,
+        success: &mut FS,
+        known: &mut HashMap<N, usize>,
+    ) -> usize
+    where
+        N: Eq + Hash + Clone,
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = N>,
+        FS: FnMut(&N) -> bool,
+    {
+        if success(node) {
+            return 1;
+        }
+        if let Some(paths) = known.get(node) {
+            return *paths;
+        }
+        let paths = successors(node)
+            .into_iter()
+            .map(|next| count(&next, successors, success, known))
+            .sum();
+        known.insert(node.clone(), paths);
+        paths
+    }
+
+    count(start, &mut successors, &mut success, &mut HashMap::new())
+}
+
+/// Every node seen so far, with the node it was first (or best) reached from.
+struct Nodes<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    parents: Vec<usize>,
+}
+
+impl<N: Eq + Hash + Clone> Nodes<N> {
+    fn new(start: &N) -> Self {
+        Self {
+            nodes: vec![start.clone()],
+            index: HashMap::from([(start.clone(), 0)]),
+            parents: vec![0],
+        }
+    }
+
+    fn insert(&mut self, node: N, parent: usize) -> usize {
+        let index = self.nodes.len();
+        self.index.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.parents.push(parent);
+        index
+    }
+
+    fn path(&self, mut node: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[node].clone()];
+        while node != 0 {
+            node = self.parents[node];
+            path.push(self.nodes[node].clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// A node waiting in the queue, ordered so the lowest estimate is popped first.
+struct Candidate<C> {
+    estimate: C,
+    cost: C,
+    node: usize,
+}
+
+impl<C: Ord> Ord for Candidate<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed for BinaryHeap's max-first order; on a tie prefer the
+        // candidate that has come further, as it is likely nearer the goal.
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for Candidate<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Candidate<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Candidate<C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Neighbourhood};
+    use std::str::FromStr;
+
+    const RISKS: &str = "1163751742\n1381373672\n2136511328\n3694931569\n7463417111\n1319128137\n1359912421\n3125421639\n1293138521\n2311944581\n";
+
+    fn risks() -> Grid<u8> {
+        Grid::from_str(RISKS).unwrap()
+    }
+
+    type Point = (isize, isize);
+
+    fn steps(grid: &Grid<u8>) -> impl FnMut(&Point) -> Vec<(Point, u32)> + '_ {
+        |&(x, y)| {
+            grid.neighbours(x, y, Neighbourhood::Orthogonal)
+                .map(|(point, risk)| (point, *risk as u32))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn dijkstra_finds_cheapest_path() {
+        let grid = risks();
+        let (path, cost) = dijkstra(&(0, 0), steps(&grid), |&p| p == (9, 9)).unwrap();
+        assert_eq!(cost, 40);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(9, 9)));
+        let total: u32 = path
+            .iter()
+            .skip(1)
+            .map(|&(x, y)| *grid.get(x, y).unwrap() as u32)
+            .sum();
+        assert_eq!(total, cost);
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        let grid = risks();
+        let manhattan = |&(x, y): &(isize, isize)| ((9 - x) + (9 - y)) as u32;
+        let (path, cost) = astar(&(0, 0), steps(&grid), manhattan, |&p| p == (9, 9)).unwrap();
+        assert_eq!(cost, 40);
+        assert_eq!(path.len(), 19);
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let grid = risks();
+        assert_eq!(dijkstra(&(0, 0), steps(&grid), |&p| p == (10, 10)), None);
+        assert_eq!(
+            bfs(&0, |n| [n + 1].into_iter().filter(|n| *n < 5), |n| *n == 9),
+            None
+        );
+    }
+
+    #[test]
+    fn bfs_finds_fewest_steps() {
+        let grid = Grid::<char>::from_str("..#.\n.##.\n....\n").unwrap();
+        let open = |&(x, y): &(isize, isize)| {
+            grid.neighbours(x, y, Neighbourhood::Orthogonal)
+                .filter(|(_, c)| **c == '.')
+                .map(|(point, _)| point)
+                .collect::<Vec<_>>()
+        };
+        let path = bfs(&(0, 0), open, |&p| p == (3, 0)).unwrap();
+        assert_eq!(
+            path,
+            vec![
+                (0, 0),
+                (0, 1),
+                (0, 2),
+                (1, 2),
+                (2, 2),
+                (3, 2),
+                (3, 1),
+                (3, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn flood_fill_and_components() {
+        let grid = Grid::<char>::from_str("..#.\n.##.\n#...\n").unwrap();
+        let open = |&(x, y): &(isize, isize)| {
+            grid.neighbours(x, y, Neighbourhood::Orthogonal)
+                .filter(|(_, c)| **c == '.')
+                .map(|(point, _)| point)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(reachable(&(0, 0), open).len(), 3);
+
+        let cells = grid
+            .iter()
+            .filter(|(_, c)| **c == '.')
+            .map(|((x, y), _)| (x as isize, y as isize));
+        let sizes = connected_components(cells, open)
+            .iter()
+            .map(|component| component.len())
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, vec![3, 5]);
+    }
+
+    #[test]
+    fn counts_paths() {
+        // Each node may step to any higher node below 5
+        let paths = count_paths(&0, |n| (n + 1)..5, |n| *n == 4);
+        assert_eq!(paths, 8);
+        assert_eq!(count_paths(&0, |_| Vec::new(), |n| *n == 4), 0);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::input;
+use anyhow::{bail, Context, Result};
+use std::env;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, IsTerminal};
+
+/// The common protocol implemented by every day's `Solution`.
+///
+/// A solver is built from the puzzle input, which both of the day's parts
+/// share, and then solved once for one part to produce its answer, so generic
+/// tooling can drive any day in the same way.
+pub trait Solver: Sized {
+    type Answer: Display;
+
+    /// Build the solver from puzzle input.
+    fn parse<R: BufRead>(reader: R) -> Result<Self>;
+
+    /// Produce the answer to the first part of the puzzle.
+    fn part1(&mut self) -> Result<Self::Answer>;
+
+    /// Produce the answer to the second part of the puzzle.
+    fn part2(&mut self) -> Result<Self::Answer>;
+
+    /// Produce the answer to `part`, 1 or 2, of the puzzle.
+    fn solve(&mut self, part: u32) -> Result<Self::Answer> {
+        match part {
+            1 => self.part1(),
+            2 => self.part2(),
+            _ => bail!("There is no part {}, only 1 and 2", part),
+        }
+    }
+
+    /// Build the solver from puzzle input held in memory.
+    fn parse_str(input: &str) -> Result<Self> {
+        Self::parse(input.as_bytes())
+    }
+
+    /// Build the solver from the puzzle input stored in `filename`.
+    fn load(filename: &str) -> Result<Self> {
+        let file =
+            File::open(filename).with_context(|| format!("Failed to read from {}", filename))?;
+        Self::parse(BufReader::new(file))
+    }
+
+    /// Build the solver from puzzle input piped to standard input.
+    fn read_stdin() -> Result<Self> {
+        Self::parse(io::stdin().lock())
+    }
+
+    /// Build the solver for a day's binary, run as `dN PART [INPUT]`, and
+    /// return it with the part to solve. The input is read from the file named
+    /// by INPUT (`-` for standard input), from standard input when that is
+    /// piped, or else from the cached input for `day`.
+    fn from_args(day: u32) -> Result<(u32, Self)> {
+        let mut args = env::args().skip(1);
+        let part = match args.next().as_deref() {
+            Some("1") => 1,
+            Some("2") => 2,
+            _ => bail!("Usage: d{} PART [INPUT], where PART is 1 or 2", day),
+        };
+        let solver = match args.next() {
+            Some(filename) if filename == "-" => Self::read_stdin()?,
+            Some(filename) => Self::load(&filename)?,
+            None if !io::stdin().is_terminal() => Self::read_stdin()?,
+            None => Self::load_day(day)?,
+        };
+        Ok((part, solver))
+    }
+
+    /// Build the solver from the cached input for `day`, fetching it if needed.
+    fn load_day(day: u32) -> Result<Self> {
+        let path = input::resolve(day)?;
+        let filename = path
+            .to_str()
+            .with_context(|| format!("Input path {:?} is not valid UTF-8", path))?;
+        Self::load(filename)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Total {
+        values: Vec<i64>,
+    }
+
+    impl Solver for Total {
+        type Answer = i64;
+
+        fn parse<R: BufRead>(reader: R) -> Result<Self> {
+            let values = reader
+                .lines()
+                .map(|line| Ok(line?.trim().parse()?))
+                .collect::<Result<_>>()?;
+            Ok(Self { values })
+        }
+
+        fn part1(&mut self) -> Result<Self::Answer> {
+            Ok(self.values.iter().sum())
+        }
+
+        fn part2(&mut self) -> Result<Self::Answer> {
+            Ok(self.values.iter().product())
+        }
+    }
+
+    #[test]
+    fn parse_str() {
+        let mut total = Total::parse_str("1\n2\n3\n").unwrap();
+        assert_eq!(total.solve(1).unwrap(), 6);
+    }
+
+    #[test]
+    fn solve_parts() {
+        let mut total = Total::parse_str("2\n3\n4\n").unwrap();
+        assert_eq!(total.solve(1).unwrap(), 9);
+        assert_eq!(total.solve(2).unwrap(), 24);
+        let error = total.solve(3).unwrap_err();
+        assert_eq!(error.to_string(), "There is no part 3, only 1 and 2");
+    }
+
+    #[test]
+    fn parse_any_reader() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "4\n5\n").unwrap();
+        let reader = BufReader::new(File::open(file.path()).unwrap());
+        assert_eq!(Total::parse(reader).unwrap().solve(1).unwrap(), 9);
+        let mut total = Total::parse(io::Cursor::new(b"7\n")).unwrap();
+        assert_eq!(total.solve(2).unwrap(), 7);
+    }
+
+    #[test]
+    fn load_missing_file() {
+        let error = Total::load("does-not-exist").unwrap_err();
+        assert_eq!(error.to_string(), "Failed to read from does-not-exist");
+    }
+}